@@ -22,8 +22,9 @@ use bevy_asset_loader::{
 };
 use godot::classes::{BoxMesh, MeshInstance3D};
 use godot_bevy::prelude::{
-    GodotAssetsPlugin, GodotBevyLogPlugin, GodotNodeHandle, GodotPackedScenePlugin, GodotResource,
-    GodotScene, GodotTransformSyncPlugin, PhysicsUpdate, SceneTreeConfig, bevy_app,
+    GodotAssetsPlugin, GodotBevyLogPlugin, GodotFixedUpdateConfig, GodotNodeHandle,
+    GodotPackedScenePlugin, GodotResource, GodotScene, GodotTransformSyncPlugin, PhysicsUpdate,
+    SceneTreeConfig, bevy_app,
     godot_prelude::{ExtensionLibrary, gdextension},
     main_thread_system,
 };
@@ -55,6 +56,8 @@ impl Plugin for AvianPhysicsDemo {
                 ScenePlugin,
                 PhysicsPlugins::default(),
             ))
+            // Step Avian's FixedPostUpdate schedules once per Godot physics tick
+            .insert_resource(GodotFixedUpdateConfig::godot_physics())
             // The following 4 resource initializations are required by Avian
             .init_resource::<Assets<Mesh>>()
             .init_resource::<CollisionDiagnostics>()
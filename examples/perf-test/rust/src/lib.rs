@@ -24,5 +24,7 @@ fn build_app(app: &mut App) {
         .add_plugins(GodotBevyLogPlugin::default())
         .add_plugins(GodotAssetsPlugin)
         .add_plugins(GodotTransformSyncPlugin::default().without_auto_sync())
+        // Godot's engine metrics in Bevy's DiagnosticsStore, under `godot/`
+        .add_plugins(GodotPerformanceDiagnosticsPlugin)
         .add_plugins(ParticleRainPlugin);
 }
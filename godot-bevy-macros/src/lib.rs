@@ -37,10 +37,69 @@ pub fn main_thread_system(_attr: TokenStream, item: TokenStream) -> TokenStream
     expanded.into()
 }
 
+/// Marks the function that builds your Bevy app.
+///
+/// `#[bevy_app]` declares the GDExtension entry point and the default entrypoint, used by every
+/// `BevyApp` node whose `app_name` is empty. Additional apps can be declared with
+/// `#[bevy_app(name = "minigame")]` and selected by setting a `BevyApp` node's `app_name`.
+///
+/// `#[bevy_app(editor)]` declares an opt-in app that runs while authoring scenes in the editor,
+/// built on the restricted `GodotEditorPlugins` set.
 #[proc_macro_attribute]
-pub fn bevy_app(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn bevy_app(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input_fn = parse_macro_input!(item as syn::ItemFn);
     let name = &input_fn.sig.ident;
+
+    let mut app_name: Option<syn::LitStr> = None;
+    let mut editor = false;
+    let attr_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            app_name = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("editor") {
+            editor = true;
+            Ok(())
+        } else {
+            Err(meta.error("unsupported bevy_app attribute, expected `name = \"...\"` or `editor`"))
+        }
+    });
+    parse_macro_input!(attr with attr_parser);
+
+    if editor {
+        if let Some(app_name) = app_name {
+            return Error::new(app_name.span(), "an editor app can't be named")
+                .into_compile_error()
+                .into();
+        }
+
+        return quote! {
+            godot_bevy::inventory::submit! {
+                godot_bevy::app::BevyEditorEntrypoint {
+                    build: #name,
+                }
+            }
+
+            #input_fn
+        }
+        .into();
+    }
+
+    if let Some(app_name) = app_name {
+        // Named apps only register their entrypoint, the extension itself is declared by the
+        // default `#[bevy_app]`
+        return quote! {
+            godot_bevy::inventory::submit! {
+                godot_bevy::app::BevyAppEntrypoint {
+                    name: #app_name,
+                    build: #name,
+                }
+            }
+
+            #input_fn
+        }
+        .into();
+    }
+
     let expanded = quote! {
         struct BevyExtensionLibrary;
 
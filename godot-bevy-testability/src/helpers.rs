@@ -7,6 +7,7 @@ use crate::BevyGodotTestContext;
 use godot::prelude::*;
 use godot_bevy::plugins::collisions::CollisionEventReader;
 use godot_bevy::plugins::scene_tree::{SceneTreeEvent, SceneTreeEventReader};
+use godot_bevy::watchers::GodotWatchers;
 use godot_bevy::watchers::collision_watcher::CollisionWatcher;
 use godot_bevy::watchers::scene_tree_watcher::SceneTreeWatcher;
 use std::sync::mpsc::{Sender, channel};
@@ -68,9 +69,15 @@ pub fn setup_test_environment_with_watchers(ctx: &mut BevyGodotTestContext) -> T
     ctx.app
         .insert_non_send_resource(CollisionEventReader(collision_receiver));
 
-    // Note: The GodotSceneTreePlugin will automatically connect the scene tree signals
-    // when it runs its connect_scene_tree system in PreStartup, since we've placed the
-    // SceneTreeWatcher at the expected path: /root/BevyAppSingleton/SceneTreeWatcher
+    // Point the plugins at the watchers, like BevyApp does when it builds the app.
+    // The GodotSceneTreePlugin will then connect the scene tree signals when it runs
+    // its connect_scene_tree system in PreStartup.
+    ctx.app.insert_resource(GodotWatchers {
+        bevy_app: Some(bevy_app_singleton.instance_id()),
+        scene_tree_watcher: Some(scene_tree_watcher.instance_id()),
+        optimized_scene_tree_watcher: None,
+        collision_watcher: Some(collision_watcher.instance_id()),
+    });
 
     TestEnvironment {
         scene_tree,
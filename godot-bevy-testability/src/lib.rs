@@ -29,7 +29,7 @@ impl BevyGodotTestContext {
     pub fn initialize_godot_bevy_resources(&mut self) {
         use godot_bevy::plugins::{
             collisions::CollisionEventReader,
-            core::PhysicsDelta,
+            core::{PhysicsDelta, ProcessDelta},
             input::InputEventReader,
             scene_tree::SceneTreeEventReader,
             signals::{GodotSignalReader, GodotSignalSender},
@@ -61,8 +61,9 @@ impl BevyGodotTestContext {
             .insert_non_send_resource(CollisionEventReader(collision_receiver));
         // Note: In real runtime, CollisionWatcher sends to _collision_sender
 
-        // Initialize physics delta resource
+        // Initialize physics and process delta resources
         self.app.init_resource::<PhysicsDelta>();
+        self.app.init_resource::<ProcessDelta>();
     }
 }
 
@@ -1,30 +1,66 @@
 use crate::plugins::core::PrePhysicsUpdate;
+use crate::watchers::GodotWatchers;
 use crate::watchers::collision_watcher::CollisionWatcher;
 use crate::watchers::input_watcher::GodotInputWatcher;
 use crate::watchers::scene_tree_watcher::SceneTreeWatcher;
 use crate::{
-    GodotPlugin,
+    GodotEditorPlugin, GodotPlugin,
     plugins::{
         collisions::CollisionEventReader,
-        core::{PhysicsDelta, PhysicsUpdate},
+        core::{
+            AppLifecycleEvent, AppPanic, AppPanicPhase, PanicPolicy, PhysicsDelta, PhysicsUpdate,
+            ProcessDelta, exit_code, install_panic_location_hook, quit_on_close_request,
+            record_schedules, run_fixed_main_for_physics_tick, run_schedule_guarded,
+            schedules_lost, startup_panicked, take_caught_panic, take_panicked_schedule,
+        },
+        diagnostics::{GodotDiagnosticsPlugin, record_update_time},
         input::InputEventReader,
-        scene_tree::SceneTreeEventReader,
+        scene_tree::{SceneTreeConfig, SceneTreeEventReader},
         signals::{GodotSignalReader, GodotSignalSender},
     },
 };
 use bevy::app::App;
+use bevy::ecs::event::Events;
+use godot::classes::notify::NodeNotification;
 use godot::prelude::*;
+use std::any::Any;
 use std::sync::OnceLock;
 use std::sync::mpsc::channel;
+use std::time::Instant;
 
 // Stores the client's entrypoint (the function they decorated with the `#[bevy_app]` macro) at runtime
 pub static BEVY_INIT_FUNC: OnceLock<Box<dyn Fn(&mut App) + Send + Sync>> = OnceLock::new();
 
+/// A named entrypoint, registered with `#[bevy_app(name = "...")]` and selected per `BevyApp`
+/// node through its `app_name` property
+pub struct BevyAppEntrypoint {
+    pub name: &'static str,
+    pub build: fn(&mut App),
+}
+
+crate::inventory::collect!(BevyAppEntrypoint);
+
+/// The entrypoint registered with `#[bevy_app(editor)]`, run by `BevyApp` nodes inside the editor
+pub struct BevyEditorEntrypoint {
+    pub build: fn(&mut App),
+}
+
+crate::inventory::collect!(BevyEditorEntrypoint);
+
 #[derive(GodotClass)]
-#[class(base=Node)]
+#[class(tool, base=Node)]
 pub struct BevyApp {
     base: Base<Node>,
+    /// Name of the `#[bevy_app(name = "...")]` entrypoint to build, empty for the default `#[bevy_app]`
+    #[export]
+    app_name: GString,
+    /// Subtree mirrored into this app, relative to this node. Empty mirrors the whole scene tree.
+    #[export]
+    scene_root: NodePath,
     app: Option<App>,
+    panic_policy: PanicPolicy,
+    quit_requested: bool,
+    editor: bool,
 }
 
 impl BevyApp {
@@ -36,12 +72,211 @@ impl BevyApp {
         self.app.as_mut()
     }
 
-    fn register_scene_tree_watcher(&mut self, app: &mut App) {
+    /// The entrypoint selected by `app_name`
+    fn entrypoint(&self) -> Option<&'static (dyn Fn(&mut App) + Send + Sync)> {
+        if self.editor {
+            return crate::inventory::iter::<BevyEditorEntrypoint>
+                .into_iter()
+                .next()
+                .map(|entrypoint| &entrypoint.build as &(dyn Fn(&mut App) + Send + Sync));
+        }
+
+        if self.app_name.is_empty() {
+            return BEVY_INIT_FUNC.get().map(|func| func.as_ref());
+        }
+
+        let app_name = self.app_name.to_string();
+        crate::inventory::iter::<BevyAppEntrypoint>
+            .into_iter()
+            .find(|entrypoint| entrypoint.name == app_name)
+            .map(|entrypoint| &entrypoint.build as &(dyn Fn(&mut App) + Send + Sync))
+    }
+
+    fn build_app(&mut self) -> Option<App> {
+        let Some(app_builder_func) = self.entrypoint() else {
+            // Running in the editor is opt-in, so a missing editor entrypoint is not an error
+            if self.editor {
+                return None;
+            }
+
+            godot_error!(
+                "No #[bevy_app(name = \"{}\")] entrypoint is registered, BevyApp {} will not run",
+                self.app_name,
+                self.base().get_path()
+            );
+            return None;
+        };
+
+        // Never mirror the editor's own UI
+        if self.editor && self.scene_root.is_empty() && self.editor_scene_root().is_none() {
+            godot_warn!(
+                "BevyApp {} is not part of an edited scene, the editor app will not run",
+                self.base().get_path()
+            );
+            return None;
+        }
+
+        let mut app = App::new();
+        if self.editor {
+            app.add_plugins(GodotEditorPlugin);
+        } else {
+            app.add_plugins(GodotPlugin);
+        }
+
+        // Call the client's entrypoint (the function they decorated with the `#[bevy_app]` macro)
+        app_builder_func(&mut app);
+
+        // Finalize plugins before any further operations
+        if app.plugins_state() != bevy::app::PluginsState::Cleaned {
+            while app.plugins_state() == bevy::app::PluginsState::Adding {
+                #[cfg(not(target_arch = "wasm32"))]
+                bevy::tasks::tick_global_task_pools_on_main_thread();
+            }
+
+            app.finish();
+            app.cleanup();
+        }
+
+        let mut watchers = GodotWatchers {
+            bevy_app: Some(self.base().instance_id()),
+            ..Default::default()
+        };
+        self.register_scene_tree_watcher(&mut app, &mut watchers);
+        self.register_signal_system(&mut app);
+        self.register_collision_watcher(&mut app, &mut watchers);
+        // The GDScript watcher isn't a tool script and input belongs to the editor itself
+        if !self.editor {
+            self.register_optimized_scene_tree_watcher(&mut watchers);
+            self.register_input_event_watcher(&mut app);
+        }
+        app.insert_resource(watchers);
+        self.scope_scene_tree(&mut app);
+        app.init_resource::<PhysicsDelta>();
+        app.init_resource::<ProcessDelta>();
+        record_schedules(app.world_mut());
+        Some(app)
+    }
+
+    /// Root of the scene this node is edited in: its owner, or the edited scene's root for nodes
+    /// without one, i.e. when this node is that root.
+    fn editor_scene_root(&self) -> Option<Gd<Node>> {
+        self.base().get_owner().or_else(|| {
+            self.base()
+                .get_tree()
+                .and_then(|tree| tree.get_edited_scene_root())
+        })
+    }
+
+    /// Restricts scene tree mirroring to the subtree at `scene_root`, if set.
+    /// In the editor it defaults to the edited scene, never the editor's own UI.
+    /// The root is resolved once, when the app is built, and isn't updated when another scene
+    /// tab becomes the edited scene.
+    fn scope_scene_tree(&self, app: &mut App) {
+        if self.scene_root.is_empty() {
+            if self.editor
+                && let Some(edited_scene_root) = self.editor_scene_root()
+                && let Some(mut config) = app.world_mut().get_resource_mut::<SceneTreeConfig>()
+            {
+                config.scene_root = Some(edited_scene_root.instance_id());
+            }
+            return;
+        }
+
+        let Some(scene_root) = self.base().get_node_or_null(&self.scene_root) else {
+            godot_error!(
+                "BevyApp {} scene_root {} not found, mirroring the whole scene tree",
+                self.base().get_path(),
+                self.scene_root
+            );
+            return;
+        };
+
+        if let Some(mut config) = app.world_mut().get_resource_mut::<SceneTreeConfig>() {
+            config.scene_root = Some(scene_root.instance_id());
+        }
+    }
+
+    /// Removes the watcher nodes added by `build_app`, so a rebuilt app can register fresh ones.
+    /// Signals connected straight to nodes, like `visibility_changed`, are disconnected by the
+    /// resources tracking them when the app is dropped.
+    fn unregister_watchers(&mut self) {
+        for name in [
+            "SceneTreeWatcher",
+            "OptimizedSceneTreeWatcher",
+            "InputEventWatcher",
+            "CollisionWatcher",
+        ] {
+            if let Some(mut watcher) = self.base().get_node_or_null(name) {
+                self.base_mut().remove_child(&watcher);
+                watcher.queue_free();
+            }
+        }
+    }
+
+    /// Mirrors an `AppExit` written by Bevy to `SceneTree.quit`
+    fn quit_if_app_exited(&mut self) {
+        if self.quit_requested {
+            return;
+        }
+
+        // Never quit the editor, only stop the editor app
+        if self.editor {
+            if self.app.as_ref().and_then(App::should_exit).is_some() {
+                self.app = None;
+            }
+            return;
+        }
+
+        if let Some(exit) = self.app.as_ref().and_then(App::should_exit)
+            && let Some(mut scene_tree) = self.base().get_tree()
+        {
+            self.quit_requested = true;
+            scene_tree.quit_ex().exit_code(exit_code(&exit)).done();
+        }
+    }
+
+    fn handle_panic(&mut self, payload: Box<dyn Any + Send>, phase: AppPanicPhase) {
+        let schedule = self
+            .app
+            .as_mut()
+            .and_then(|app| take_panicked_schedule(app.world_mut()));
+        let report = AppPanic::from_payload(payload.as_ref(), phase, schedule);
+        godot_error!("{report}");
+
+        match self.panic_policy {
+            PanicPolicy::Abort => std::process::abort(),
+            PanicPolicy::DropApp => {
+                self.app = None;
+                std::panic::resume_unwind(payload);
+            }
+            PanicPolicy::SkipFrame
+                if self.app.as_ref().is_some_and(|app| {
+                    !schedules_lost(app.world()) && !startup_panicked(app.world())
+                }) =>
+            {
+                if let Some(app) = self.app.as_mut() {
+                    app.world_mut().send_event(report);
+                }
+            }
+            PanicPolicy::SkipFrame | PanicPolicy::RestartApp => {
+                self.app = None;
+                self.unregister_watchers();
+
+                self.app = self.build_app().map(|mut app| {
+                    app.world_mut().send_event(report);
+                    app
+                });
+            }
+        }
+    }
+
+    fn register_scene_tree_watcher(&mut self, app: &mut App, watchers: &mut GodotWatchers) {
         let (sender, receiver) = channel();
         let mut scene_tree_watcher = SceneTreeWatcher::new_alloc();
         scene_tree_watcher.bind_mut().notification_channel = Some(sender);
         scene_tree_watcher.set_name("SceneTreeWatcher");
         self.base_mut().add_child(&scene_tree_watcher);
+        watchers.scene_tree_watcher = Some(scene_tree_watcher.instance_id());
         app.insert_non_send_resource(SceneTreeEventReader(receiver));
     }
 
@@ -62,16 +297,17 @@ impl BevyApp {
         app.insert_non_send_resource(InputEventReader(receiver));
     }
 
-    fn register_collision_watcher(&mut self, app: &mut App) {
+    fn register_collision_watcher(&mut self, app: &mut App, watchers: &mut GodotWatchers) {
         let (sender, receiver) = channel();
         let mut collision_watcher = CollisionWatcher::new_alloc();
         collision_watcher.bind_mut().notification_channel = Some(sender);
         collision_watcher.set_name("CollisionWatcher");
         self.base_mut().add_child(&collision_watcher);
+        watchers.collision_watcher = Some(collision_watcher.instance_id());
         app.insert_non_send_resource(CollisionEventReader(receiver));
     }
 
-    fn register_optimized_scene_tree_watcher(&mut self) {
+    fn register_optimized_scene_tree_watcher(&mut self, watchers: &mut GodotWatchers) {
         // Try to load the OptimizedSceneTreeWatcher GDScript class
         let mut resource_loader = godot::classes::ResourceLoader::singleton();
         if let Some(resource) =
@@ -82,6 +318,7 @@ impl BevyApp {
         {
             node.set_name("OptimizedSceneTreeWatcher");
             self.base_mut().add_child(&node);
+            watchers.optimized_scene_tree_watcher = Some(node.instance_id());
             tracing::info!("Successfully registered OptimizedSceneTreeWatcher");
             return;
         }
@@ -96,53 +333,69 @@ impl INode for BevyApp {
     fn init(base: Base<Node>) -> Self {
         Self {
             base,
+            app_name: GString::new(),
+            scene_root: NodePath::default(),
             app: Default::default(),
+            panic_policy: Default::default(),
+            quit_requested: false,
+            editor: false,
         }
     }
 
     fn ready(&mut self) {
-        if godot::classes::Engine::singleton().is_editor_hint() {
+        self.editor = godot::classes::Engine::singleton().is_editor_hint();
+        // `tool` makes every BevyApp in an edited scene run this; only an explicit
+        // `#[bevy_app(editor)]` entrypoint builds an app there
+        if self.editor
+            && crate::inventory::iter::<BevyEditorEntrypoint>
+                .into_iter()
+                .next()
+                .is_none()
+        {
             return;
         }
 
-        let mut app = App::new();
-        app.add_plugins(GodotPlugin);
-
-        // Call the client's entrypoint (the function they decorated with the `#[bevy_app]` macro)
-        let app_builder_func = BEVY_INIT_FUNC.get().unwrap();
-        app_builder_func(&mut app);
-
-        // Finalize plugins before any further operations
-        if app.plugins_state() != bevy::app::PluginsState::Cleaned {
-            while app.plugins_state() == bevy::app::PluginsState::Adding {
-                #[cfg(not(target_arch = "wasm32"))]
-                bevy::tasks::tick_global_task_pools_on_main_thread();
+        let Some(app) = self.build_app() else {
+            return;
+        };
+        self.panic_policy = app
+            .world()
+            .get_resource::<PanicPolicy>()
+            .copied()
+            .unwrap_or_default();
+        if self.editor {
+            // A panicking editor app must never take the editor down with it, and the editor's
+            // own panics are none of our business, so leave the process-wide hook alone
+            if self.panic_policy == PanicPolicy::Abort {
+                self.panic_policy = PanicPolicy::DropApp;
             }
-
-            app.finish();
-            app.cleanup();
+        } else if self.panic_policy != PanicPolicy::Abort {
+            // Locations are only reported for panics we recover from
+            install_panic_location_hook();
         }
-
-        self.register_scene_tree_watcher(&mut app);
-        self.register_optimized_scene_tree_watcher();
-        self.register_signal_system(&mut app);
-        self.register_input_event_watcher(&mut app);
-        self.register_collision_watcher(&mut app);
-        app.init_resource::<PhysicsDelta>();
         self.app = Some(app);
     }
 
-    fn process(&mut self, _delta: f64) {
-        use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
-
-        if godot::classes::Engine::singleton().is_editor_hint() {
-            return;
-        }
+    fn process(&mut self, delta: f64) {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
 
         if let Some(app) = self.app.as_mut()
             && let Err(e) = catch_unwind(AssertUnwindSafe(|| {
+                // Update process delta resource with Godot's delta
+                app.world_mut().resource_mut::<ProcessDelta>().delta_seconds = delta;
+
                 // Run the full Bevy update cycle - much simpler!
+                let started = Instant::now();
                 app.update();
+                // Panics in systems are caught per schedule, so the schedules survive them
+                if let Some(payload) = take_caught_panic(app.world_mut()) {
+                    std::panic::resume_unwind(payload);
+                }
+                record_update_time(
+                    app.world_mut(),
+                    &GodotDiagnosticsPlugin::PROCESS_UPDATE_TIME,
+                    started.elapsed(),
+                );
 
                 #[cfg(feature = "trace_tracy")]
                 // Indicate that rendering of a continuous frame has ended.
@@ -151,19 +404,14 @@ impl INode for BevyApp {
                     .frame_mark();
             }))
         {
-            self.app = None;
-
-            eprintln!("bevy app update panicked");
-            resume_unwind(e);
+            self.handle_panic(e, AppPanicPhase::Process);
         }
+
+        self.quit_if_app_exited();
     }
 
     fn physics_process(&mut self, delta: f32) {
-        use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
-
-        if godot::classes::Engine::singleton().is_editor_hint() {
-            return;
-        }
+        use std::panic::{AssertUnwindSafe, catch_unwind};
 
         if let Some(app) = self.app.as_mut()
             && let Err(e) = catch_unwind(AssertUnwindSafe(|| {
@@ -171,8 +419,21 @@ impl INode for BevyApp {
                 app.world_mut().resource_mut::<PhysicsDelta>().delta_seconds = delta;
 
                 // Run only our physics-specific schedule
-                app.world_mut().run_schedule(PrePhysicsUpdate);
-                app.world_mut().run_schedule(PhysicsUpdate);
+                let started = Instant::now();
+                run_schedule_guarded(app.world_mut(), PrePhysicsUpdate);
+                run_schedule_guarded(app.world_mut(), PhysicsUpdate);
+
+                // Step Bevy's FixedMain in lockstep with Godot physics, if enabled.
+                // Its schedules are skipped if one of the above panicked.
+                run_fixed_main_for_physics_tick(app.world_mut());
+                if let Some(payload) = take_caught_panic(app.world_mut()) {
+                    std::panic::resume_unwind(payload);
+                }
+                record_update_time(
+                    app.world_mut(),
+                    &GodotDiagnosticsPlugin::PHYSICS_UPDATE_TIME,
+                    started.elapsed(),
+                );
 
                 #[cfg(feature = "trace_tracy")]
                 // Indicate that a physics frame has ended.
@@ -181,10 +442,39 @@ impl INode for BevyApp {
                     .secondary_frame_mark(tracing_tracy::client::frame_name!("physics"));
             }))
         {
-            self.app = None;
+            self.handle_panic(e, AppPanicPhase::PhysicsProcess);
+        }
+
+        self.quit_if_app_exited();
+    }
+
+    fn on_notification(&mut self, what: NodeNotification) {
+        let Some(lifecycle_event) = AppLifecycleEvent::from_notification(what) else {
+            return;
+        };
+
+        // `GodotLifecyclePlugin` disabled `auto_accept_quit`. With the app dropped (or never
+        // built), or the node unable to process (e.g. the tree is paused), no frame runs that
+        // could act on the close request, so quit here
+        if lifecycle_event == AppLifecycleEvent::CloseRequested
+            && !self.editor
+            && !self.quit_requested
+            && quit_on_close_request(self.app.is_some(), self.base().can_process())
+            && let Some(mut scene_tree) = self.base().get_tree()
+        {
+            self.quit_requested = true;
+            scene_tree.quit();
+        }
 
-            eprintln!("bevy app physics update panicked");
-            resume_unwind(e);
+        let Some(app) = self.app.as_mut() else {
+            return;
+        };
+
+        if let Some(mut events) = app
+            .world_mut()
+            .get_resource_mut::<Events<AppLifecycleEvent>>()
+        {
+            events.send(lifecycle_event);
         }
     }
 }
@@ -0,0 +1,120 @@
+use super::GodotNodeHandle;
+use bevy::ecs::{component::HookContext, entity::Entity, resource::Resource, world::DeferredWorld};
+use godot::{
+    classes::Node,
+    obj::{Gd, Inherits, InstanceId},
+};
+use std::collections::HashMap;
+
+/// Maps Godot nodes to the entities holding their [`GodotNodeHandle`].
+///
+/// Kept up to date by component hooks whenever a handle is inserted, replaced or removed, so
+/// lookups are O(1) and always reflect the world once commands have been applied.
+///
+/// ```ignore
+/// fn on_body_clicked(index: Res<GodotEntityIndex>, mut events: EventReader<BodyClicked>) {
+///     for event in events.read() {
+///         if let Some(entity) = index.entity_for_node(&event.body) {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct GodotEntityIndex {
+    entities: HashMap<InstanceId, Entity>,
+}
+
+impl GodotEntityIndex {
+    /// The entity mirroring the node with this instance id
+    pub fn entity_for(&self, instance_id: InstanceId) -> Option<Entity> {
+        self.entities.get(&instance_id).copied()
+    }
+
+    /// The entity mirroring this node
+    pub fn entity_for_node<T: Inherits<Node>>(&self, node: &Gd<T>) -> Option<Entity> {
+        self.entity_for(node.instance_id())
+    }
+
+    /// Number of nodes with an entity
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+pub(super) fn on_node_handle_insert(mut world: DeferredWorld, context: HookContext) {
+    let Some(instance_id) = world
+        .get::<GodotNodeHandle>(context.entity)
+        .map(GodotNodeHandle::instance_id)
+    else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<GodotEntityIndex>() {
+        index.entities.insert(instance_id, context.entity);
+    }
+}
+
+pub(super) fn on_node_handle_replace(mut world: DeferredWorld, context: HookContext) {
+    let Some(instance_id) = world
+        .get::<GodotNodeHandle>(context.entity)
+        .map(GodotNodeHandle::instance_id)
+    else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<GodotEntityIndex>()
+        // Another entity may have taken over the node in the meantime
+        && index.entities.get(&instance_id) == Some(&context.entity)
+    {
+        index.entities.remove(&instance_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::world::World;
+
+    #[test]
+    fn tracks_handle_insert_replace_and_despawn() {
+        let mut world = World::new();
+        world.init_resource::<GodotEntityIndex>();
+        let first = InstanceId::from_i64(1);
+        let second = InstanceId::from_i64(2);
+
+        let entity = world.spawn(GodotNodeHandle::from_instance_id(first)).id();
+        assert_eq!(
+            world.resource::<GodotEntityIndex>().entity_for(first),
+            Some(entity)
+        );
+
+        world
+            .entity_mut(entity)
+            .insert(GodotNodeHandle::from_instance_id(second));
+        let index = world.resource::<GodotEntityIndex>();
+        assert_eq!(index.entity_for(first), None);
+        assert_eq!(index.entity_for(second), Some(entity));
+
+        world.despawn(entity);
+        assert!(world.resource::<GodotEntityIndex>().is_empty());
+    }
+
+    #[test]
+    fn keeps_entry_taken_over_by_another_entity() {
+        let mut world = World::new();
+        world.init_resource::<GodotEntityIndex>();
+        let id = InstanceId::from_i64(1);
+
+        let old = world.spawn(GodotNodeHandle::from_instance_id(id)).id();
+        let new = world.spawn(GodotNodeHandle::from_instance_id(id)).id();
+        world.despawn(old);
+
+        assert_eq!(
+            world.resource::<GodotEntityIndex>().entity_for(id),
+            Some(new)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::entity_index::{on_node_handle_insert, on_node_handle_replace};
 use bevy::ecs::component::Component;
 use godot::{
     classes::Node,
@@ -5,6 +6,7 @@ use godot::{
 };
 
 #[derive(Debug, Component, Clone, PartialEq, Eq)]
+#[component(on_insert = on_node_handle_insert, on_replace = on_node_handle_replace)]
 pub struct GodotNodeHandle {
     instance_id: InstanceId,
 }
@@ -1,3 +1,6 @@
+pub mod entity_index;
+pub use entity_index::GodotEntityIndex;
+
 pub mod godot_node_handle;
 pub use godot_node_handle::*;
 
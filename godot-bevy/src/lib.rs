@@ -29,3 +29,12 @@ impl Plugin for GodotPlugin {
         app.add_plugins(plugins::GodotCorePlugins);
     }
 }
+
+/// Editor counterpart of [`GodotPlugin`], added to the `#[bevy_app(editor)]` app
+pub struct GodotEditorPlugin;
+
+impl Plugin for GodotEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(plugins::GodotEditorPlugins);
+    }
+}
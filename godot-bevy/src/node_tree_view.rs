@@ -16,11 +16,41 @@ pub fn find_node_by_pattern(
     base_node: &godot::obj::Gd<godot::classes::Node>,
     pattern: &str,
 ) -> Option<godot::obj::Gd<godot::classes::Node>> {
+    let mut found = None;
+    visit_nodes_by_pattern(base_node, pattern, &mut |node| {
+        found = Some(node);
+        true
+    });
+    found
+}
+
+/// Find all nodes matching a pattern with wildcards, in tree order.
+///
+/// Uses the same patterns as [`find_node_by_pattern`].
+pub fn find_nodes_by_pattern(
+    base_node: &godot::obj::Gd<godot::classes::Node>,
+    pattern: &str,
+) -> Vec<godot::obj::Gd<godot::classes::Node>> {
+    let mut found = Vec::new();
+    visit_nodes_by_pattern(base_node, pattern, &mut |node| {
+        found.push(node);
+        false
+    });
+    found
+}
+
+/// Calls `visit` for each node matching the pattern, until it returns true
+fn visit_nodes_by_pattern(
+    base_node: &godot::obj::Gd<godot::classes::Node>,
+    pattern: &str,
+    visit: &mut dyn FnMut(godot::obj::Gd<godot::classes::Node>) -> bool,
+) {
     // Handle absolute vs relative paths
     let (search_root, pattern_parts) = if let Some(stripped) = pattern.strip_prefix('/') {
         // Absolute path - start from scene tree root
-        let scene_tree = base_node.get_tree()?;
-        let root = scene_tree.get_root()?;
+        let Some(root) = base_node.get_tree().and_then(|tree| tree.get_root()) else {
+            return;
+        };
         let root_as_node = root.upcast::<godot::classes::Node>();
         let mut parts: Vec<&str> = stripped.split('/').filter(|s| !s.is_empty()).collect();
 
@@ -36,17 +66,19 @@ pub fn find_node_by_pattern(
         (base_node.clone(), parts)
     };
 
-    find_node_recursive(&search_root, &pattern_parts, 0)
+    visit_node_recursive(&search_root, &pattern_parts, 0, visit);
 }
 
-fn find_node_recursive(
+/// Returns true once `visit` asked to stop
+fn visit_node_recursive(
     current_node: &godot::obj::Gd<godot::classes::Node>,
     pattern_parts: &[&str],
     depth: usize,
-) -> Option<godot::obj::Gd<godot::classes::Node>> {
-    // If we've matched all pattern parts, we found our target
+    visit: &mut dyn FnMut(godot::obj::Gd<godot::classes::Node>) -> bool,
+) -> bool {
+    // If we've matched all pattern parts, we found a target
     if depth >= pattern_parts.len() {
-        return Some(current_node.clone());
+        return visit(current_node.clone());
     }
 
     let pattern_part = pattern_parts[depth];
@@ -56,9 +88,9 @@ fn find_node_recursive(
         // Try all children
         for i in 0..current_node.get_child_count() {
             if let Some(child) = current_node.get_child(i)
-                && let Some(result) = find_node_recursive(&child, pattern_parts, depth + 1)
+                && visit_node_recursive(&child, pattern_parts, depth + 1, visit)
             {
-                return Some(result);
+                return true;
             }
         }
     } else if pattern_part.contains('*') {
@@ -67,9 +99,9 @@ fn find_node_recursive(
             if let Some(child) = current_node.get_child(i) {
                 let child_name = child.get_name().to_string();
                 if matches_wildcard_pattern(&child_name, pattern_part)
-                    && let Some(result) = find_node_recursive(&child, pattern_parts, depth + 1)
+                    && visit_node_recursive(&child, pattern_parts, depth + 1, visit)
                 {
-                    return Some(result);
+                    return true;
                 }
             }
         }
@@ -77,13 +109,11 @@ fn find_node_recursive(
         // Exact name match
         if current_node.has_node(pattern_part) {
             let child = current_node.get_node_as::<godot::classes::Node>(pattern_part);
-            if let Some(result) = find_node_recursive(&child, pattern_parts, depth + 1) {
-                return Some(result);
-            }
+            return visit_node_recursive(&child, pattern_parts, depth + 1, visit);
         }
     }
 
-    None
+    false
 }
 
 fn matches_wildcard_pattern(text: &str, pattern: &str) -> bool {
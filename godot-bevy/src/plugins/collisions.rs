@@ -1,4 +1,4 @@
-use crate::interop::GodotNodeHandle;
+use crate::interop::{GodotEntityIndex, GodotNodeHandle};
 use crate::plugins::core::PrePhysicsUpdate;
 use bevy::{
     app::{App, Plugin},
@@ -7,7 +7,7 @@ use bevy::{
         entity::Entity,
         event::{Event, EventReader, EventWriter, event_update_system},
         schedule::IntoScheduleConfigs,
-        system::{NonSendMut, Query},
+        system::{NonSendMut, Query, Res},
     },
 };
 use godot::prelude::*;
@@ -45,6 +45,7 @@ impl Plugin for GodotCollisionsPlugin {
                 update_godot_collisions,
             ),
         )
+        .init_resource::<GodotEntityIndex>()
         .add_event::<CollisionEvent>();
     }
 }
@@ -75,31 +76,20 @@ pub enum CollisionEventType {
 
 fn update_godot_collisions(
     mut events: EventReader<CollisionEvent>,
-    mut entities: Query<(&GodotNodeHandle, &mut Collisions)>,
-    all_entities: Query<(Entity, &GodotNodeHandle)>,
+    mut entities: Query<&mut Collisions>,
+    index: Res<GodotEntityIndex>,
 ) {
-    for (_, mut collisions) in entities.iter_mut() {
+    for mut collisions in entities.iter_mut() {
         collisions.recent_collisions = vec![];
     }
 
     for event in events.read() {
         trace!(target: "godot_collisions_update", event = ?event);
 
-        let target = all_entities.iter().find_map(|(ent, reference)| {
-            if reference == &event.target {
-                Some(ent)
-            } else {
-                None
-            }
-        });
-
-        let collisions = entities.iter_mut().find_map(|(reference, collisions)| {
-            if reference == &event.origin {
-                Some(collisions)
-            } else {
-                None
-            }
-        });
+        let target = index.entity_for(event.target.instance_id());
+        let collisions = index
+            .entity_for(event.origin.instance_id())
+            .and_then(|origin| entities.get_mut(origin).ok());
 
         let (target, mut collisions) = match (target, collisions) {
             (Some(target), Some(collisions)) => (target, collisions),
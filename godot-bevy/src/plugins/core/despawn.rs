@@ -0,0 +1,110 @@
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+use bevy::ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    observer::Trigger,
+    query::With,
+    resource::Resource,
+    system::{Query, Res, ResMut},
+    world::OnRemove,
+};
+use godot::classes::Node;
+use godot::obj::Gd;
+use std::collections::HashMap;
+use tracing::debug;
+
+/// What happens to a Godot node when its entity is despawned, or its `GodotNodeHandle` removed.
+///
+/// Insert it as a resource to change the default for all entities, or as a component to
+/// override it for one entity:
+/// ```ignore
+/// #[bevy_app]
+/// fn build_app(app: &mut App) {
+///     // GDScript owns the nodes, Bevy only mirrors them
+///     app.insert_resource(NodeDespawnPolicy::Keep);
+/// }
+///
+/// fn return_bullet_to_pool(mut commands: Commands, bullet: Entity) {
+///     commands.entity(bullet).insert(NodeDespawnPolicy::Detach).despawn();
+/// }
+/// ```
+///
+/// Child entities despawned along with their parent follow it: if the parent's node is detached
+/// or kept, so is the rest of its subtree, unless a child has a policy of its own.
+#[derive(Component, Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeDespawnPolicy {
+    /// `queue_free` the node, along with its children
+    #[default]
+    Free,
+    /// Remove the node from its parent but keep it alive, e.g. to pool it or hand it over to
+    /// GDScript. Freeing it later is up to you.
+    Detach,
+    /// Leave the node where it is, only the ECS side goes away
+    Keep,
+}
+
+/// Policies applied to entities despawned during the current command flush, so their children
+/// (despawned right after them) can follow along
+#[derive(Resource, Default)]
+pub(crate) struct DespawnedNodePolicies(HashMap<Entity, NodeDespawnPolicy>);
+
+pub(crate) fn clear_despawned_node_policies(mut despawned: ResMut<DespawnedNodePolicies>) {
+    despawned.0.clear();
+}
+
+/// Applies the [`NodeDespawnPolicy`] to the node of an entity losing its `GodotNodeHandle`
+#[main_thread_system]
+pub(crate) fn on_godot_node_handle_removed(
+    trigger: Trigger<OnRemove, GodotNodeHandle>,
+    query: Query<(
+        &GodotNodeHandle,
+        Option<&NodeDespawnPolicy>,
+        Option<&ChildOf>,
+    )>,
+    parents: Query<(), With<Children>>,
+    entities: Query<Entity>,
+    default_policy: Res<NodeDespawnPolicy>,
+    mut despawned: ResMut<DespawnedNodePolicies>,
+) {
+    let entity = trigger.target();
+    let Ok((handle, policy, child_of)) = query.get(entity) else {
+        return;
+    };
+
+    let policy = policy.copied().unwrap_or_else(|| {
+        // A parent that no longer exists means this entity is despawned along with it
+        let inherited = child_of
+            .map(ChildOf::parent)
+            .filter(|parent| !entities.contains(*parent))
+            .and_then(|parent| despawned.0.get(&parent).copied());
+        match inherited {
+            // The parent detached or kept its node, and this one is part of that subtree
+            Some(NodeDespawnPolicy::Detach | NodeDespawnPolicy::Keep) => NodeDespawnPolicy::Keep,
+            Some(NodeDespawnPolicy::Free) | None => *default_policy,
+        }
+    });
+    if parents.contains(entity) {
+        despawned.0.insert(entity, policy);
+    }
+
+    let Ok(mut node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+        return;
+    };
+    match policy {
+        NodeDespawnPolicy::Free => {
+            debug!(
+                "Freeing Godot node with instance_id {:?}",
+                handle.instance_id()
+            );
+            node.queue_free();
+        }
+        NodeDespawnPolicy::Detach => {
+            if let Some(mut parent) = node.get_parent() {
+                parent.remove_child(&node);
+            }
+        }
+        NodeDespawnPolicy::Keep => {}
+    }
+}
@@ -0,0 +1,164 @@
+use bevy::app::{App, AppExit, Last, Plugin, PreStartup};
+use bevy::ecs::event::{Event, EventReader, EventWriter};
+use bevy::ecs::system::Res;
+use bevy::prelude::Resource;
+use godot::classes::notify::NodeNotification;
+
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::plugins::scene_tree::plugin::SceneTreeRefImpl;
+use crate::prelude::main_thread_system;
+
+/// Application lifecycle notifications received by the `BevyApp` node, forwarded as Bevy events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub enum AppLifecycleEvent {
+    /// `NOTIFICATION_WM_CLOSE_REQUEST`: the user asked to close the window
+    CloseRequested,
+    /// `NOTIFICATION_APPLICATION_PAUSED`: the OS paused the application (mobile)
+    Paused,
+    /// `NOTIFICATION_APPLICATION_RESUMED`: the OS resumed the application (mobile)
+    Resumed,
+    /// `NOTIFICATION_APPLICATION_FOCUS_IN`: the application gained focus
+    FocusIn,
+    /// `NOTIFICATION_APPLICATION_FOCUS_OUT`: the application lost focus
+    FocusOut,
+}
+
+impl AppLifecycleEvent {
+    pub fn from_notification(what: NodeNotification) -> Option<Self> {
+        match what {
+            NodeNotification::WM_CLOSE_REQUEST => Some(Self::CloseRequested),
+            NodeNotification::APPLICATION_PAUSED => Some(Self::Paused),
+            NodeNotification::APPLICATION_RESUMED => Some(Self::Resumed),
+            NodeNotification::APPLICATION_FOCUS_IN => Some(Self::FocusIn),
+            NodeNotification::APPLICATION_FOCUS_OUT => Some(Self::FocusOut),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration resource for the lifecycle bridge
+#[derive(Resource, Debug, Clone)]
+pub struct GodotLifecycleConfig {
+    /// When true (default), a close request writes `AppExit::Success` at the end of the frame
+    /// it was received in, after your systems had a chance to react to
+    /// [`AppLifecycleEvent::CloseRequested`]. Set to false to decide yourself, e.g. after a
+    /// confirmation dialog, by writing `AppExit`.
+    pub exit_on_close_request: bool,
+}
+
+impl Default for GodotLifecycleConfig {
+    fn default() -> Self {
+        Self {
+            exit_on_close_request: true,
+        }
+    }
+}
+
+/// Bridges application lifecycle between Godot and Bevy:
+/// - `AppExit` written in Bevy makes the `BevyApp` node call `SceneTree.quit(code)`
+/// - Close requests and application pause/resume/focus notifications become [`AppLifecycleEvent`]s
+///
+/// Godot's `auto_accept_quit` is disabled so close requests go through Bevy first. If the app
+/// is dropped after a panic, or the `BevyApp` node can't process (e.g. the tree is paused and
+/// the node is pausable), `BevyApp` quits on close requests itself since no frame would run.
+#[derive(Default)]
+pub struct GodotLifecyclePlugin;
+
+impl Plugin for GodotLifecyclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AppLifecycleEvent>()
+            .init_resource::<GodotLifecycleConfig>()
+            .init_non_send_resource::<SceneTreeRefImpl>()
+            .add_systems(PreStartup, disable_auto_accept_quit)
+            .add_systems(Last, exit_on_close_request);
+    }
+}
+
+#[main_thread_system]
+fn disable_auto_accept_quit(mut scene_tree: SceneTreeRef) {
+    scene_tree.get().set_auto_accept_quit(false);
+}
+
+fn exit_on_close_request(
+    config: Res<GodotLifecycleConfig>,
+    mut lifecycle_events: EventReader<AppLifecycleEvent>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    let close_requested = lifecycle_events
+        .read()
+        .any(|event| *event == AppLifecycleEvent::CloseRequested);
+
+    if close_requested && config.exit_on_close_request {
+        app_exit.write(AppExit::Success);
+    }
+}
+
+/// Whether `BevyApp` must quit on a close request itself instead of leaving it to
+/// [`exit_on_close_request`], which only runs when the app updates
+pub(crate) fn quit_on_close_request(app_running: bool, can_process: bool) -> bool {
+    !app_running || !can_process
+}
+
+/// Converts an `AppExit` into the exit code passed to `SceneTree.quit`
+pub(crate) fn exit_code(exit: &AppExit) -> i32 {
+    match exit {
+        AppExit::Success => 0,
+        AppExit::Error(code) => code.get() as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZero;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(exit_code(&AppExit::Success), 0);
+        assert_eq!(exit_code(&AppExit::Error(NonZero::new(3).unwrap())), 3);
+        assert_eq!(exit_code(&AppExit::Error(NonZero::<u8>::MAX)), 255);
+    }
+
+    #[test]
+    fn test_quit_on_close_request() {
+        // Running app gets the close request as an event and decides itself
+        assert!(!quit_on_close_request(true, true));
+        // Paused tree with a pausable BevyApp: no frame runs, so quit right away
+        assert!(quit_on_close_request(true, false));
+        // Dropped app
+        assert!(quit_on_close_request(false, true));
+        assert!(quit_on_close_request(false, false));
+    }
+
+    #[test]
+    fn test_from_notification() {
+        let cases = [
+            (
+                NodeNotification::WM_CLOSE_REQUEST,
+                Some(AppLifecycleEvent::CloseRequested),
+            ),
+            (
+                NodeNotification::APPLICATION_PAUSED,
+                Some(AppLifecycleEvent::Paused),
+            ),
+            (
+                NodeNotification::APPLICATION_RESUMED,
+                Some(AppLifecycleEvent::Resumed),
+            ),
+            (
+                NodeNotification::APPLICATION_FOCUS_IN,
+                Some(AppLifecycleEvent::FocusIn),
+            ),
+            (
+                NodeNotification::APPLICATION_FOCUS_OUT,
+                Some(AppLifecycleEvent::FocusOut),
+            ),
+            (NodeNotification::READY, None),
+            (NodeNotification::PROCESS, None),
+        ];
+
+        for (notification, event) in cases {
+            assert_eq!(AppLifecycleEvent::from_notification(notification), event);
+        }
+    }
+}
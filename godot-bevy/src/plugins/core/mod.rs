@@ -0,0 +1,838 @@
+#![allow(deprecated)] // TODO: remove this once we've removed SystemDeltaTimer
+
+use bevy::app::{
+    App, FixedMain, Plugin, RunFixedMainLoop, RunFixedMainLoopSystem, ScheduleRunnerPlugin,
+};
+use bevy::ecs::schedule::{Schedule, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub mod despawn;
+pub mod lifecycle;
+pub mod panic;
+pub mod time;
+pub use despawn::NodeDespawnPolicy;
+use despawn::{DespawnedNodePolicies, clear_despawned_node_policies, on_godot_node_handle_removed};
+pub use lifecycle::{AppLifecycleEvent, GodotLifecycleConfig, GodotLifecyclePlugin};
+pub(crate) use lifecycle::{exit_code, quit_on_close_request};
+pub use panic::{AppPanic, AppPanicPhase, PanicPolicy};
+pub(crate) use panic::{
+    install_panic_location_hook, record_schedules, run_schedule_guarded, schedules_lost,
+    startup_panicked, take_caught_panic, take_panicked_schedule,
+};
+pub use time::{GodotTimePlugin, ProcessDelta};
+
+/// Schedule that runs during Godot's physics_process at physics frame rate.
+/// This schedule runs just before the PhysicsUpdate schedule.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrePhysicsUpdate;
+
+/// Schedule that runs during Godot's physics_process at physics frame rate.
+/// Use this for movement, physics, and systems that need to sync with Godot's physics timing.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PhysicsUpdate;
+
+/// Resource containing Godot's physics delta time for the current frame
+#[derive(Resource, Default)]
+pub struct PhysicsDelta {
+    pub delta_seconds: f32,
+}
+
+impl PhysicsDelta {
+    pub fn new(delta: f64) -> Self {
+        Self {
+            delta_seconds: delta as f32,
+        }
+    }
+
+    pub fn delta(&self) -> Duration {
+        Duration::from_secs_f32(self.delta_seconds)
+    }
+}
+
+/// Controls which loop drives Bevy's `FixedMain` schedule (and with it `FixedUpdate`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FixedUpdateMode {
+    /// Bevy's own accumulator runs `FixedMain` during `app.update()` at `Time<Fixed>`'s timestep
+    #[default]
+    Bevy,
+    /// `FixedMain` runs exactly once per Godot physics tick, right after `PhysicsUpdate`,
+    /// with `Time<Fixed>` stepped by `PhysicsDelta`.
+    /// Best for: crates built on `FixedUpdate` (e.g. Avian) that must stay in lockstep with Godot physics
+    GodotPhysics,
+}
+
+/// Configuration resource for how `FixedMain` is scheduled.
+/// Insert it from your `#[bevy_app]` entrypoint to change the mode.
+#[derive(Default, Resource, Debug, Clone)]
+pub struct GodotFixedUpdateConfig {
+    pub mode: FixedUpdateMode,
+}
+
+impl GodotFixedUpdateConfig {
+    /// Let Bevy's accumulator drive `FixedMain` - default behavior
+    pub fn bevy() -> Self {
+        Self {
+            mode: FixedUpdateMode::Bevy,
+        }
+    }
+
+    /// Run `FixedMain` once per Godot physics tick
+    pub fn godot_physics() -> Self {
+        Self {
+            mode: FixedUpdateMode::GodotPhysics,
+        }
+    }
+}
+
+/// Runs `FixedMain` once, stepping `Time<Fixed>` by the current `PhysicsDelta`.
+/// Called from `BevyApp::physics_process` when [`FixedUpdateMode::GodotPhysics`] is active.
+pub(crate) fn run_fixed_main_for_physics_tick(world: &mut World) {
+    if world
+        .get_resource::<GodotFixedUpdateConfig>()
+        .is_none_or(|config| config.mode != FixedUpdateMode::GodotPhysics)
+    {
+        return;
+    }
+
+    // Mirror Bevy's behavior of not running fixed steps while virtual time is paused
+    if world.resource::<Time<Virtual>>().is_paused() {
+        return;
+    }
+
+    let delta = world.resource::<PhysicsDelta>().delta();
+    if delta.is_zero() {
+        return;
+    }
+
+    let mut fixed_time = world.resource_mut::<Time<Fixed>>();
+    fixed_time.set_timestep(delta);
+    fixed_time.advance_by(delta);
+
+    *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+    let _ = world.try_run_schedule(FixedMain);
+    *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+}
+
+fn bevy_fixed_main_enabled(config: Res<GodotFixedUpdateConfig>) -> bool {
+    config.mode == FixedUpdateMode::Bevy
+}
+
+/// Resource marker to ensure systems accessing Godot APIs run on the main thread
+#[derive(Resource, Default, Debug)]
+pub struct MainThreadMarker;
+
+use crate::interop::{GodotEntityIndex, GodotNodeHandle};
+use crate::plugins::scene_tree::groups::sync_groups;
+use crate::plugins::scene_tree::meta::{FromMeta, MetaComponentSource, sync_meta_component};
+use crate::plugins::scene_tree::{GroupAdded, GroupRemoved, Groups, SceneTreeConfig};
+use bevy::ecs::component::Mutable;
+use bevy::ecs::system::EntityCommands;
+use godot::builtin::Variant;
+use godot::meta::FromGodot;
+use godot::{
+    classes::{ClassDb, Node, Script},
+    obj::{Gd, Inherits},
+};
+use std::sync::Arc;
+
+/// Function that adds a component to an entity with access to the Godot node
+type ComponentInserter = Box<dyn Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync>;
+
+/// Function that adds a component to an entity, given a node already known to be of its class
+type ClassComponentInserter = Box<dyn Fn(&mut EntityCommands, Gd<Node>) + Send + Sync>;
+
+struct ClassComponent {
+    component: TypeId,
+    class: TypeId,
+    class_name: String,
+    inserter: ClassComponentInserter,
+}
+
+struct ScriptComponent {
+    component: TypeId,
+    class_name: String,
+    inserter: ComponentInserter,
+}
+
+struct GroupComponent {
+    component: TypeId,
+    group: String,
+    inserter: ComponentInserter,
+}
+
+/// Registry for components that should be added to entities spawned from the scene tree
+#[derive(Resource, Default)]
+pub struct SceneTreeComponentRegistry {
+    /// Components to add to every entity spawned from scene tree
+    /// Stored as (TypeId, inserter) to avoid duplicates
+    components: Vec<(TypeId, ComponentInserter)>,
+    /// Components only added to nodes of a given class (or a class inheriting from it)
+    class_components: Vec<ClassComponent>,
+    /// Components only added to nodes with a script of a given `class_name` (or extending it)
+    script_components: Vec<ScriptComponent>,
+    /// Components only added to nodes in a given group when they enter the tree
+    group_components: Vec<GroupComponent>,
+    /// Indices into `class_components` matching each concrete class, worked out through
+    /// `ClassDB` the first time a node of that class is seen
+    class_matches: Mutex<HashMap<String, Arc<[usize]>>>,
+}
+
+impl SceneTreeComponentRegistry {
+    /// Register a component type to be added to all scene tree entities
+    pub fn register<C>(&mut self)
+    where
+        C: Component + Default,
+    {
+        let type_id = TypeId::of::<C>();
+
+        // Check if already registered
+        if self.components.iter().any(|(id, _)| *id == type_id) {
+            return;
+        }
+
+        let inserter = Box::new(|entity: &mut EntityCommands, _node: &GodotNodeHandle| {
+            entity.insert(C::default());
+        });
+        self.components.push((type_id, inserter));
+    }
+
+    /// Register a component type with custom initialization logic
+    pub fn register_with_init<C, F>(&mut self, init_fn: F)
+    where
+        C: Component,
+        F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<C>();
+
+        // Check if already registered
+        if self.components.iter().any(|(id, _)| *id == type_id) {
+            return;
+        }
+
+        let inserter = Box::new(init_fn);
+        self.components.push((type_id, inserter));
+    }
+
+    /// Register a component with initialization logic that only runs for nodes of class `T`,
+    /// including classes inheriting from it. The node is handed over already cast to `T`.
+    pub fn register_for<T, C, F>(&mut self, init_fn: F)
+    where
+        T: Inherits<Node>,
+        C: Component,
+        F: Fn(&mut EntityCommands, Gd<T>) + Send + Sync + 'static,
+    {
+        let (component, class) = (TypeId::of::<C>(), TypeId::of::<T>());
+
+        // Check if already registered for this class
+        if self
+            .class_components
+            .iter()
+            .any(|registered| registered.component == component && registered.class == class)
+        {
+            return;
+        }
+
+        self.class_components.push(ClassComponent {
+            component,
+            class,
+            class_name: T::class_name().to_string(),
+            inserter: Box::new(move |entity, node| init_fn(entity, node.cast::<T>())),
+        });
+        self.class_matches.get_mut().unwrap().clear();
+    }
+
+    /// Register a component with initialization logic that only runs for nodes whose script
+    /// declares the given `class_name`, or extends a script that does
+    pub fn register_for_script<C, F>(&mut self, class_name: &str, init_fn: F)
+    where
+        C: Component,
+        F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static,
+    {
+        let component = TypeId::of::<C>();
+
+        // Check if already registered for this script class
+        if self.script_components.iter().any(|registered| {
+            registered.component == component && registered.class_name == class_name
+        }) {
+            return;
+        }
+
+        self.script_components.push(ScriptComponent {
+            component,
+            class_name: class_name.to_string(),
+            inserter: Box::new(init_fn),
+        });
+    }
+
+    /// Register a component with initialization logic that only runs for nodes that are in
+    /// `group` when they enter the tree. The same component can be registered for several groups.
+    pub fn register_for_group<C, F>(&mut self, group: &str, init_fn: F)
+    where
+        C: Component,
+        F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static,
+    {
+        let component = TypeId::of::<C>();
+
+        // Check if already registered for this group
+        if self
+            .group_components
+            .iter()
+            .any(|registered| registered.component == component && registered.group == group)
+        {
+            return;
+        }
+
+        self.group_components.push(GroupComponent {
+            component,
+            group: group.to_string(),
+            inserter: Box::new(init_fn),
+        });
+    }
+
+    /// Add all registered components to an entity
+    pub fn add_to_entity(&self, entity: &mut EntityCommands, node: &GodotNodeHandle) {
+        self.add_to_entity_of_class(entity, node, None);
+    }
+
+    /// Add all registered components to an entity. `node_class` is the node's exact class, when
+    /// already known, and spares a `get_class` call.
+    pub(crate) fn add_to_entity_of_class(
+        &self,
+        entity: &mut EntityCommands,
+        node: &GodotNodeHandle,
+        node_class: Option<&str>,
+    ) {
+        for (_, inserter) in &self.components {
+            inserter(entity, node);
+        }
+
+        if self.class_components.is_empty()
+            && self.script_components.is_empty()
+            && self.group_components.is_empty()
+        {
+            return;
+        }
+        let Ok(godot_node) = Gd::<Node>::try_from_instance_id(node.instance_id()) else {
+            return;
+        };
+
+        if !self.script_components.is_empty() {
+            let script_classes = script_class_names(&godot_node);
+            for registered in &self.script_components {
+                if script_classes.contains(&registered.class_name) {
+                    (registered.inserter)(entity, node);
+                }
+            }
+        }
+
+        for registered in &self.group_components {
+            if godot_node.is_in_group(registered.group.as_str()) {
+                (registered.inserter)(entity, node);
+            }
+        }
+
+        if self.class_components.is_empty() {
+            return;
+        }
+        let fetched_class;
+        let node_class = match node_class {
+            Some(node_class) => node_class,
+            None => {
+                fetched_class = godot_node.get_class().to_string();
+                &fetched_class
+            }
+        };
+        for &index in self.class_matches(node_class).iter() {
+            (self.class_components[index].inserter)(entity, godot_node.clone());
+        }
+    }
+
+    /// Indices of the class-scoped registrations that apply to nodes of exactly `class`
+    fn class_matches(&self, class: &str) -> Arc<[usize]> {
+        let mut class_matches = self.class_matches.lock().unwrap();
+        if let Some(matches) = class_matches.get(class) {
+            return matches.clone();
+        }
+
+        let class_db = ClassDb::singleton();
+        let matches: Arc<[usize]> = self
+            .class_components
+            .iter()
+            .enumerate()
+            .filter(|(_, registered)| {
+                registered.class_name == class
+                    || class_db.is_parent_class(class, registered.class_name.as_str())
+            })
+            .map(|(index, _)| index)
+            .collect();
+        class_matches.insert(class.to_string(), matches.clone());
+        matches
+    }
+}
+
+/// `class_name`s of the node's script and the scripts it extends
+fn script_class_names(node: &Gd<Node>) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut script = node.get_script().try_to::<Gd<Script>>().ok();
+    while let Some(current) = script {
+        let name = current.get_global_name();
+        if !name.is_empty() {
+            names.push(name.to_string());
+        }
+        script = current.get_base_script();
+    }
+    names
+}
+
+/// Extension trait for App to register scene tree components
+pub trait AppSceneTreeExt {
+    /// Register a component to be added to all scene tree entities with default value
+    fn register_scene_tree_component<C>(&mut self) -> &mut Self
+    where
+        C: Component + Default;
+
+    /// Register a component with custom initialization logic that has access to the Godot node
+    fn register_scene_tree_component_with_init<C, F>(&mut self, init_fn: F) -> &mut Self
+    where
+        C: Component,
+        F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static;
+
+    /// Register a component with initialization logic that only runs for nodes of class `T`
+    /// (or inheriting from it), receiving the node already cast to `T`. Class-scoped
+    /// initializers run after the ones registered for all nodes, so they can override them.
+    fn register_scene_tree_component_for<T, C, F>(&mut self, init_fn: F) -> &mut Self
+    where
+        T: Inherits<Node>,
+        C: Component,
+        F: Fn(&mut EntityCommands, Gd<T>) + Send + Sync + 'static;
+
+    /// Register a marker component added to nodes of class `T`, which can be one of your own
+    /// `#[derive(GodotClass)]` types, and to nodes of classes inheriting from it
+    fn register_class_marker<T, M>(&mut self) -> &mut Self
+    where
+        T: Inherits<Node>,
+        M: Component + Default;
+
+    /// Register a marker component added to nodes whose GDScript declares `class_name`, or
+    /// extends a script that does
+    fn register_script_class_marker<M>(&mut self, class_name: &str) -> &mut Self
+    where
+        M: Component + Default;
+
+    /// Register a marker component that is present exactly while the node is in the given
+    /// Godot group, so designer-authored groups can be used as query filters, e.g.
+    /// `Query<&GodotNodeHandle, With<Enemy>>`. Registering the same marker for several groups
+    /// makes it present while the node is in any of them. Runtime membership changes are only
+    /// followed for entities with `TrackGroups`, see `SceneTreeConfig::sync_groups`.
+    fn register_group_marker<C>(&mut self, group: &str) -> &mut Self
+    where
+        C: Component + Default;
+
+    /// Register a component read from the node metadata under `key`, e.g. set by designers in
+    /// the inspector. Nodes without that metadata don't get the component. Later metadata
+    /// changes are followed for entities with `TrackMeta`, see `SceneTreeConfig::sync_meta`.
+    /// Each component type is read from one key; registering it again is ignored.
+    fn register_meta_component<C>(&mut self, key: &str) -> &mut Self
+    where
+        C: Component<Mutability = Mutable> + FromGodot + PartialEq;
+
+    /// Like [`register_meta_component`](Self::register_meta_component), with a custom conversion
+    /// from the metadata value. Returning `None` leaves the component out.
+    fn register_meta_component_with<C, F>(&mut self, key: &str, convert: F) -> &mut Self
+    where
+        C: Component<Mutability = Mutable> + PartialEq,
+        F: Fn(&Variant) -> Option<C> + Send + Sync + 'static;
+}
+
+impl AppSceneTreeExt for App {
+    fn register_scene_tree_component<C>(&mut self) -> &mut Self
+    where
+        C: Component + Default,
+    {
+        // Get or create the registry
+        if !self
+            .world()
+            .contains_resource::<SceneTreeComponentRegistry>()
+        {
+            self.world_mut()
+                .init_resource::<SceneTreeComponentRegistry>();
+        }
+
+        self.world_mut()
+            .resource_mut::<SceneTreeComponentRegistry>()
+            .register::<C>();
+
+        self
+    }
+
+    fn register_scene_tree_component_with_init<C, F>(&mut self, init_fn: F) -> &mut Self
+    where
+        C: Component,
+        F: Fn(&mut EntityCommands, &GodotNodeHandle) + Send + Sync + 'static,
+    {
+        // Get or create the registry
+        if !self
+            .world()
+            .contains_resource::<SceneTreeComponentRegistry>()
+        {
+            self.world_mut()
+                .init_resource::<SceneTreeComponentRegistry>();
+        }
+
+        self.world_mut()
+            .resource_mut::<SceneTreeComponentRegistry>()
+            .register_with_init::<C, F>(init_fn);
+
+        self
+    }
+
+    fn register_scene_tree_component_for<T, C, F>(&mut self, init_fn: F) -> &mut Self
+    where
+        T: Inherits<Node>,
+        C: Component,
+        F: Fn(&mut EntityCommands, Gd<T>) + Send + Sync + 'static,
+    {
+        // Get or create the registry
+        if !self
+            .world()
+            .contains_resource::<SceneTreeComponentRegistry>()
+        {
+            self.world_mut()
+                .init_resource::<SceneTreeComponentRegistry>();
+        }
+
+        self.world_mut()
+            .resource_mut::<SceneTreeComponentRegistry>()
+            .register_for::<T, C, F>(init_fn);
+
+        self
+    }
+
+    fn register_class_marker<T, M>(&mut self) -> &mut Self
+    where
+        T: Inherits<Node>,
+        M: Component + Default,
+    {
+        self.register_scene_tree_component_for::<T, M, _>(|entity, _node| {
+            entity.insert(M::default());
+        })
+    }
+
+    fn register_script_class_marker<M>(&mut self, class_name: &str) -> &mut Self
+    where
+        M: Component + Default,
+    {
+        // Get or create the registry
+        if !self
+            .world()
+            .contains_resource::<SceneTreeComponentRegistry>()
+        {
+            self.world_mut()
+                .init_resource::<SceneTreeComponentRegistry>();
+        }
+
+        self.world_mut()
+            .resource_mut::<SceneTreeComponentRegistry>()
+            .register_for_script::<M, _>(class_name, |entity, _node| {
+                entity.insert(M::default());
+            });
+
+        self
+    }
+
+    fn register_group_marker<C>(&mut self, group: &str) -> &mut Self
+    where
+        C: Component + Default,
+    {
+        // Get or create the registry
+        if !self
+            .world()
+            .contains_resource::<SceneTreeComponentRegistry>()
+        {
+            self.world_mut()
+                .init_resource::<SceneTreeComponentRegistry>();
+        }
+
+        self.world_mut()
+            .resource_mut::<SceneTreeComponentRegistry>()
+            .register_for_group::<C, _>(group, |entity, _node| {
+                entity.insert(C::default());
+            });
+
+        // Later membership changes are picked up through the events sent by group syncing,
+        // with one system per marker however many groups it stands for
+        if let Some(mut marker) = self.world_mut().get_resource_mut::<GroupMarker<C>>() {
+            if !marker.groups.iter().any(|registered| registered == group) {
+                marker.groups.push(group.to_string());
+            }
+            return self;
+        }
+
+        self.insert_resource(GroupMarker::<C> {
+            groups: vec![group.to_string()],
+            marker: PhantomData,
+        })
+        .add_systems(First, sync_group_marker::<C>.after(sync_groups))
+    }
+
+    fn register_meta_component<C>(&mut self, key: &str) -> &mut Self
+    where
+        C: Component<Mutability = Mutable> + FromGodot + PartialEq,
+    {
+        self.register_meta_component_with::<C, _>(key, |value| value.try_to::<C>().ok())
+    }
+
+    fn register_meta_component_with<C, F>(&mut self, key: &str, convert: F) -> &mut Self
+    where
+        C: Component<Mutability = Mutable> + PartialEq,
+        F: Fn(&Variant) -> Option<C> + Send + Sync + 'static,
+    {
+        // One source per component type, as both the sync system and the spawn initializer
+        // are registered per type
+        if let Some(registered) = self.world().get_resource::<MetaComponentSource<C>>() {
+            warn!(
+                "{} is already read from metadata '{}', ignoring its registration for '{key}'",
+                std::any::type_name::<C>(),
+                registered.key
+            );
+            return self;
+        }
+
+        let source = MetaComponentSource {
+            key: key.to_string(),
+            convert: Arc::new(convert),
+        };
+        self.insert_resource(source.clone());
+
+        self.register_scene_tree_component_with_init::<C, _>(move |entity, node| {
+            if let Ok(node) = Gd::<Node>::try_from_instance_id(node.instance_id())
+                && let Some(component) = source.read(&node)
+            {
+                entity.insert((component, FromMeta::<C>::default()));
+            }
+        })
+        .add_systems(First, sync_meta_component::<C>.after(sync_groups))
+    }
+}
+
+/// Groups a marker component was registered for with `register_group_marker`
+#[derive(Resource)]
+struct GroupMarker<C> {
+    groups: Vec<String>,
+    marker: PhantomData<fn() -> C>,
+}
+
+fn sync_group_marker<C: Component + Default>(
+    mut commands: Commands,
+    marker: Res<GroupMarker<C>>,
+    mut added: EventReader<GroupAdded>,
+    mut removed: EventReader<GroupRemoved>,
+    groups: Query<&Groups>,
+) {
+    let is_marker_group = |group: &str| marker.groups.iter().any(|registered| registered == group);
+
+    for event in added.read().filter(|event| is_marker_group(&event.group)) {
+        commands.entity(event.entity).try_insert(C::default());
+    }
+    for event in removed.read().filter(|event| is_marker_group(&event.group)) {
+        // Keep the marker while the node is still in another of its groups
+        let in_other_group = groups
+            .get(event.entity)
+            .is_ok_and(|groups| groups.iter().any(is_marker_group));
+        if !in_other_group {
+            commands.entity(event.entity).try_remove::<C>();
+        }
+    }
+}
+
+/// Group markers only follow runtime membership changes while groups are polled
+fn warn_unsynced_group_markers(
+    registry: Res<SceneTreeComponentRegistry>,
+    config: Option<Res<SceneTreeConfig>>,
+) {
+    if registry.group_components.is_empty() || config.is_none_or(|config| config.sync_groups) {
+        return;
+    }
+
+    let groups: Vec<_> = registry
+        .group_components
+        .iter()
+        .map(|registered| registered.group.as_str())
+        .collect();
+    warn!(
+        "Group markers are registered for {groups:?}, but SceneTreeConfig::sync_groups is off, \
+         so they only follow add_to_group/remove_from_group calls on entities with TrackGroups"
+    );
+}
+
+/// Minimal core plugin with only essential Godot-Bevy integration.
+/// This includes scene tree management, basic Bevy setup, and core resources.
+/// `FixedMain` scheduling is configured through the [`GodotFixedUpdateConfig`] resource.
+#[derive(Default)]
+pub struct GodotBaseCorePlugin;
+
+impl Plugin for GodotBaseCorePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MinimalPlugins.build().disable::<ScheduleRunnerPlugin>())
+            .add_plugins(bevy::diagnostic::DiagnosticsPlugin)
+            .init_resource::<PhysicsDelta>()
+            .init_non_send_resource::<MainThreadMarker>()
+            .init_resource::<SceneTreeComponentRegistry>()
+            .init_resource::<GodotEntityIndex>()
+            .init_resource::<GodotFixedUpdateConfig>()
+            .init_resource::<PanicPolicy>()
+            .add_event::<AppPanic>()
+            .init_resource::<NodeDespawnPolicy>()
+            .init_resource::<DespawnedNodePolicies>()
+            .add_observer(on_godot_node_handle_removed)
+            .add_systems(First, clear_despawned_node_policies)
+            .add_systems(Startup, warn_unsynced_group_markers);
+
+        // Add the PhysicsUpdate schedule
+        app.add_schedule(Schedule::new(PrePhysicsUpdate));
+        app.add_schedule(Schedule::new(PhysicsUpdate));
+
+        // Keep schedules around when a system panics, so PanicPolicy::SkipFrame can continue
+        panic::guard_main_schedules(app);
+
+        // Bevy's fixed loop is skipped when Godot's physics tick drives FixedMain instead
+        app.configure_sets(
+            RunFixedMainLoop,
+            RunFixedMainLoopSystem::FixedMainLoop.run_if(bevy_fixed_main_enabled),
+        );
+    }
+}
+
+/// SystemParam to keep track of an independent delta time
+///
+/// Not every system runs on a Bevy update and Bevy can be updated multiple
+/// during a "frame".
+#[derive(SystemParam)]
+#[deprecated(note = "Use PhysicsDelta instead")]
+pub struct SystemDeltaTimer<'w, 's> {
+    last_time: Local<'s, Option<Instant>>,
+    marker: PhantomData<&'w ()>,
+}
+
+#[allow(deprecated)]
+impl<'w, 's> SystemDeltaTimer<'w, 's> {
+    /// Returns the time passed since the last invocation
+    pub fn delta(&mut self) -> Duration {
+        let now = Instant::now();
+        let last_time = self.last_time.unwrap_or(now);
+
+        *self.last_time = Some(now);
+
+        now - last_time
+    }
+
+    pub fn delta_seconds(&mut self) -> f32 {
+        self.delta().as_secs_f32()
+    }
+
+    pub fn delta_seconds_f64(&mut self) -> f64 {
+        self.delta().as_secs_f64()
+    }
+}
+
+pub trait FindEntityByNameExt<T> {
+    fn find_entity_by_name(self, name: &str) -> Option<T>;
+}
+
+impl<'a, T: 'a, U> FindEntityByNameExt<T> for U
+where
+    U: Iterator<Item = (&'a Name, T)>,
+{
+    fn find_entity_by_name(mut self, name: &str) -> Option<T> {
+        self.find_map(|(ent_name, t)| (ent_name.as_str() == name).then_some(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct FixedRuns(u32);
+
+    fn physics_tick_world(mode: FixedUpdateMode) -> World {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Time<Fixed>>();
+        world.init_resource::<Time<Virtual>>();
+        world.insert_resource(PhysicsDelta::new(1.0 / 60.0));
+        world.insert_resource(GodotFixedUpdateConfig { mode });
+        world.init_resource::<FixedRuns>();
+
+        let mut fixed_main = Schedule::new(FixedMain);
+        fixed_main.add_systems(|mut runs: ResMut<FixedRuns>, time: Res<Time>| {
+            assert_eq!(time.delta(), PhysicsDelta::new(1.0 / 60.0).delta());
+            runs.0 += 1;
+        });
+        world.add_schedule(fixed_main);
+        world
+    }
+
+    #[test]
+    fn test_physics_tick_runs_fixed_main_once() {
+        let mut world = physics_tick_world(FixedUpdateMode::GodotPhysics);
+        let delta = world.resource::<PhysicsDelta>().delta();
+
+        run_fixed_main_for_physics_tick(&mut world);
+        run_fixed_main_for_physics_tick(&mut world);
+
+        assert_eq!(world.resource::<FixedRuns>().0, 2);
+        assert_eq!(world.resource::<Time<Fixed>>().elapsed(), delta * 2);
+        // The generic clock is handed back to virtual time afterwards
+        assert_eq!(world.resource::<Time>().delta(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_physics_tick_skipped_in_bevy_mode_or_when_paused() {
+        let mut world = physics_tick_world(FixedUpdateMode::Bevy);
+        run_fixed_main_for_physics_tick(&mut world);
+        assert_eq!(world.resource::<FixedRuns>().0, 0);
+
+        let mut world = physics_tick_world(FixedUpdateMode::GodotPhysics);
+        world.resource_mut::<Time<Virtual>>().pause();
+        run_fixed_main_for_physics_tick(&mut world);
+        assert_eq!(world.resource::<FixedRuns>().0, 0);
+    }
+
+    #[derive(Component, PartialEq)]
+    struct Health(i64);
+
+    #[test]
+    fn test_meta_component_registered_once() {
+        let first_systems = |app: &App| app.get_schedule(First).unwrap().systems_len();
+        let mut app = App::new();
+        app.register_meta_component_with::<Health, _>("health", |value| {
+            value.try_to::<i64>().ok().map(Health)
+        });
+        let systems = first_systems(&app);
+        app.register_meta_component_with::<Health, _>("hp", |value| {
+            value.try_to::<i64>().ok().map(Health)
+        });
+
+        // The second registration adds neither a sync system nor an initializer
+        assert_eq!(first_systems(&app), systems);
+
+        assert_eq!(
+            app.world().resource::<MetaComponentSource<Health>>().key,
+            "health"
+        );
+        assert_eq!(
+            app.world()
+                .resource::<SceneTreeComponentRegistry>()
+                .components
+                .len(),
+            1
+        );
+    }
+}
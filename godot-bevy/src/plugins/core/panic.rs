@@ -0,0 +1,355 @@
+use crate::plugins::diagnostics::record_schedule_time;
+use bevy::app::{App, FixedMain, FixedMainScheduleOrder, Main, MainScheduleOrder};
+use bevy::ecs::event::Event;
+use bevy::ecs::schedule::{
+    ExecutorKind, InternedScheduleLabel, Schedule, ScheduleLabel, Schedules,
+};
+use bevy::ecs::system::Local;
+use bevy::ecs::world::{Mut, World};
+use bevy::prelude::Resource;
+use parking_lot::Mutex;
+use std::any::Any;
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Once;
+use std::time::Instant;
+
+/// What `BevyApp` does when a Bevy update panics.
+///
+/// Insert it as a resource from your `#[bevy_app]` entrypoint:
+/// ```ignore
+/// #[bevy_app]
+/// fn build_app(app: &mut App) {
+///     app.insert_resource(PanicPolicy::SkipFrame);
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PanicPolicy {
+    /// Report the panic and abort the whole process
+    Abort,
+    /// Report the panic, drop the Bevy app and keep Godot running without it
+    #[default]
+    DropApp,
+    /// Report the panic and keep the current app, continuing with the next frame.
+    /// World state may be partially updated by the frame that panicked. If a startup schedule
+    /// panicked, or a schedule run from inside a system was lost to the panic, the app is
+    /// restarted like with `RestartApp`.
+    SkipFrame,
+    /// Report the panic, drop the Bevy app and build a fresh one from the entrypoint.
+    /// The new app re-mirrors the scene tree, like on startup.
+    RestartApp,
+}
+
+/// The `BevyApp` callback a panic happened in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppPanicPhase {
+    /// During `app.update()`, called from Godot's `_process`
+    Process,
+    /// During the physics schedules, called from Godot's `_physics_process`
+    PhysicsProcess,
+}
+
+/// Event describing a panic caught by `BevyApp`.
+///
+/// With [`PanicPolicy::SkipFrame`] it is written to the same app on the next frame,
+/// with [`PanicPolicy::RestartApp`] it is written to the freshly built app.
+#[derive(Debug, Clone, Event)]
+pub struct AppPanic {
+    /// The panic message
+    pub message: String,
+    /// Source location (`file:line:column`) of the panic, pointing at the offending system
+    pub location: Option<String>,
+    /// Schedule the panic happened in, e.g. `Update`
+    pub schedule: Option<String>,
+    pub phase: AppPanicPhase,
+}
+
+impl AppPanic {
+    pub(crate) fn from_payload(
+        payload: &(dyn Any + Send),
+        phase: AppPanicPhase,
+        schedule: Option<String>,
+    ) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic payload".to_string()
+        };
+
+        Self {
+            message,
+            location: LAST_PANIC_LOCATION.lock().take(),
+            schedule,
+            phase,
+        }
+    }
+}
+
+impl fmt::Display for AppPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase = match self.phase {
+            AppPanicPhase::Process => "process",
+            AppPanicPhase::PhysicsProcess => "physics process",
+        };
+        write!(f, "bevy app {phase} panicked")?;
+        if let Some(schedule) = &self.schedule {
+            write!(f, " in schedule `{schedule}`")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(location) = &self.location {
+            write!(f, " (at {location})")?;
+        }
+        Ok(())
+    }
+}
+
+// Location of the most recent panic, recorded by our hook. Panics in systems may happen on any
+// task pool thread, so this can't be a thread local.
+static LAST_PANIC_LOCATION: Mutex<Option<String>> = Mutex::new(None);
+
+/// Chains a panic hook that records panic locations for [`AppPanic`] reports
+pub(crate) fn install_panic_location_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |panic_info| {
+            *LAST_PANIC_LOCATION.lock() = panic_info.location().map(|location| {
+                format!(
+                    "{}:{}:{}",
+                    location.file(),
+                    location.line(),
+                    location.column()
+                )
+            });
+            previous_hook(panic_info);
+        }));
+    });
+}
+
+/// Panic caught while running the app's schedules, handed to `BevyApp` once the update returns
+#[derive(Resource, Default)]
+pub(crate) struct CaughtPanic {
+    payload: Mutex<Option<Box<dyn Any + Send>>>,
+    /// Schedule the pending panic happened in
+    schedule: Option<InternedScheduleLabel>,
+    /// Whether a startup schedule panicked, leaving the app half initialized
+    startup_panicked: bool,
+    /// Schedules the app was built with, to notice ones lost to a panic
+    schedules: Vec<InternedScheduleLabel>,
+}
+
+/// Replaces the runners of `Main` and `FixedMain` with ones that keep schedules intact when a
+/// system panics. Bevy's own runners remove each schedule from [`Schedules`] while it runs, so
+/// a panic unwinding through them would drop it, and every later update would fail.
+pub(crate) fn guard_main_schedules(app: &mut App) {
+    let mut main_schedule = Schedule::new(Main);
+    main_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    main_schedule.add_systems(run_main_guarded);
+    let mut fixed_main_schedule = Schedule::new(FixedMain);
+    fixed_main_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    fixed_main_schedule.add_systems(run_fixed_main_guarded);
+
+    app.add_schedule(main_schedule)
+        .add_schedule(fixed_main_schedule)
+        .init_resource::<CaughtPanic>();
+}
+
+/// Records the schedules of a fully built app, see [`schedules_lost`]
+pub(crate) fn record_schedules(world: &mut World) {
+    let schedules = world
+        .resource::<Schedules>()
+        .iter()
+        .map(|(_, schedule)| schedule.label())
+        .collect();
+    world.resource_mut::<CaughtPanic>().schedules = schedules;
+}
+
+/// Whether a schedule recorded by [`record_schedules`] went missing. Schedules run from inside
+/// a system, like `OnEnter`, are still dropped when that system panics.
+pub(crate) fn schedules_lost(world: &World) -> bool {
+    let schedules = world.resource::<Schedules>();
+    world
+        .resource::<CaughtPanic>()
+        .schedules
+        .iter()
+        .any(|label| !schedules.contains(*label))
+}
+
+/// Whether a startup schedule panicked, so `Startup` systems after it never ran
+pub(crate) fn startup_panicked(world: &World) -> bool {
+    world.resource::<CaughtPanic>().startup_panicked
+}
+
+/// Takes the label of the schedule the last caught panic happened in, e.g. `Update`
+pub(crate) fn take_panicked_schedule(world: &mut World) -> Option<String> {
+    world
+        .get_resource_mut::<CaughtPanic>()
+        .and_then(|mut caught| caught.schedule.take())
+        .map(|label| format!("{label:?}"))
+}
+
+/// Takes the panic caught by the guarded runners during the last schedule run, if any
+pub(crate) fn take_caught_panic(world: &mut World) -> Option<Box<dyn Any + Send>> {
+    world
+        .get_resource_mut::<CaughtPanic>()
+        .and_then(|caught| caught.payload.lock().take())
+}
+
+/// Runs `label` like `World::try_run_schedule`, but puts the schedule back if one of its systems
+/// panics and stores the panic in [`CaughtPanic`]. Does nothing once a panic is pending, so the
+/// rest of the frame is skipped.
+pub(crate) fn run_schedule_guarded(world: &mut World, label: impl ScheduleLabel) {
+    if world.resource::<CaughtPanic>().payload.lock().is_some() {
+        return;
+    }
+    let label = label.intern();
+    let Some(mut schedule) = world.resource_mut::<Schedules>().remove(label) else {
+        return;
+    };
+
+    let started = Instant::now();
+    let result = catch_unwind(AssertUnwindSafe(|| schedule.run(world)));
+    record_schedule_time(world, label, started.elapsed());
+    if result.is_err() {
+        reset_executor(&mut schedule);
+    }
+    world.resource_mut::<Schedules>().insert(schedule);
+
+    if let Err(payload) = result {
+        let mut caught = world.resource_mut::<CaughtPanic>();
+        *caught.payload.lock() = Some(payload);
+        caught.schedule = Some(label);
+    }
+}
+
+/// Replaces the executor of a schedule whose run was cut short by a panic.
+///
+/// Bevy's executors don't clean up after a panicking system: `SingleThreadedExecutor` keeps
+/// the systems of the aborted run marked as completed and would skip them from then on.
+/// `Schedule::set_executor_kind` only builds a fresh executor when the kind changes, so switch
+/// to another kind and back.
+fn reset_executor(schedule: &mut Schedule) {
+    let kind = schedule.get_executor_kind();
+    let other = match kind {
+        ExecutorKind::SingleThreaded => ExecutorKind::MultiThreaded,
+        _ => ExecutorKind::SingleThreaded,
+    };
+    schedule.set_executor_kind(other).set_executor_kind(kind);
+}
+
+/// `Main::run_main`, running each schedule through [`run_schedule_guarded`]
+fn run_main_guarded(world: &mut World, mut run_at_least_once: Local<bool>) {
+    if !*run_at_least_once {
+        world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
+            for &label in &order.startup_labels {
+                run_schedule_guarded(world, label);
+            }
+        });
+        // A panicking startup schedule skips the rest, so startup is only complete without one
+        let mut caught = world.resource_mut::<CaughtPanic>();
+        if caught.payload.get_mut().is_some() {
+            caught.startup_panicked = true;
+            return;
+        }
+        *run_at_least_once = true;
+    }
+
+    world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
+        for &label in &order.labels {
+            run_schedule_guarded(world, label);
+        }
+    });
+}
+
+/// `FixedMain::run_fixed_main`, running each schedule through [`run_schedule_guarded`]
+fn run_fixed_main_guarded(world: &mut World) {
+    let started = Instant::now();
+    world.resource_scope(|world, order: Mut<FixedMainScheduleOrder>| {
+        for &label in &order.labels {
+            run_schedule_guarded(world, label);
+        }
+    });
+    record_schedule_time(world, FixedMain.intern(), started.elapsed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::ResMut;
+
+    #[test]
+    fn test_message_from_payload() {
+        let payload: Box<dyn Any + Send> = Box::new("static message");
+        let report = AppPanic::from_payload(payload.as_ref(), AppPanicPhase::Process, None);
+        assert_eq!(report.message, "static message");
+
+        let payload: Box<dyn Any + Send> = Box::new(format!("formatted {}", 42));
+        let report = AppPanic::from_payload(payload.as_ref(), AppPanicPhase::Process, None);
+        assert_eq!(report.message, "formatted 42");
+
+        let payload: Box<dyn Any + Send> = Box::new(42);
+        let report = AppPanic::from_payload(payload.as_ref(), AppPanicPhase::Process, None);
+        assert_eq!(report.message, "unknown panic payload");
+    }
+
+    #[test]
+    fn test_display() {
+        let report = AppPanic {
+            message: "boom".to_string(),
+            location: Some("src/lib.rs:1:2".to_string()),
+            schedule: Some("FixedUpdate".to_string()),
+            phase: AppPanicPhase::PhysicsProcess,
+        };
+        assert_eq!(
+            report.to_string(),
+            "bevy app physics process panicked in schedule `FixedUpdate`: boom (at src/lib.rs:1:2)"
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    #[test]
+    fn test_schedules_survive_panicking_system() {
+        let mut app = App::new();
+        guard_main_schedules(&mut app);
+        app.init_resource::<Runs>()
+            .add_systems(bevy::app::Update, |mut runs: ResMut<Runs>| {
+                runs.0 += 1;
+                if runs.0 == 1 {
+                    panic!("first run");
+                }
+            });
+        record_schedules(app.world_mut());
+
+        app.update();
+        assert!(take_caught_panic(app.world_mut()).is_some());
+        assert_eq!(
+            take_panicked_schedule(app.world_mut()).as_deref(),
+            Some("Update")
+        );
+        assert!(!schedules_lost(app.world()));
+        assert!(!startup_panicked(app.world()));
+
+        app.update();
+        assert!(take_caught_panic(app.world_mut()).is_none());
+        assert_eq!(app.world().resource::<Runs>().0, 2);
+    }
+
+    #[test]
+    fn test_startup_panic_is_recorded() {
+        let mut app = App::new();
+        guard_main_schedules(&mut app);
+        app.init_resource::<Runs>()
+            .add_systems(bevy::app::Startup, || panic!("startup"))
+            .add_systems(bevy::app::Update, |mut runs: ResMut<Runs>| runs.0 += 1);
+        record_schedules(app.world_mut());
+
+        app.update();
+        assert!(take_caught_panic(app.world_mut()).is_some());
+        assert!(startup_panicked(app.world()));
+        assert_eq!(app.world().resource::<Runs>().0, 0);
+    }
+}
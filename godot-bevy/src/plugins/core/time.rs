@@ -0,0 +1,94 @@
+use bevy::app::{App, First, Plugin};
+use bevy::ecs::schedule::IntoScheduleConfigs;
+use bevy::ecs::system::{Local, Res, ResMut};
+use bevy::prelude::Resource;
+use bevy::time::{Time, TimeSystem, TimeUpdateStrategy, Virtual};
+use godot::classes::Engine;
+use std::time::Duration;
+
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::plugins::scene_tree::plugin::SceneTreeRefImpl;
+use crate::prelude::main_thread_system;
+
+/// Resource containing the delta Godot passed to the most recent `_process` call.
+/// Note that Godot has already scaled this value by `Engine.time_scale`.
+#[derive(Resource, Default)]
+pub struct ProcessDelta {
+    pub delta_seconds: f64,
+}
+
+impl ProcessDelta {
+    pub fn new(delta: f64) -> Self {
+        Self {
+            delta_seconds: delta,
+        }
+    }
+
+    pub fn delta(&self) -> Duration {
+        Duration::from_secs_f64(self.delta_seconds)
+    }
+}
+
+/// Drives Bevy's `Time<Real>` and `Time<Virtual>` from Godot instead of the wall clock:
+/// - `Time<Real>` advances by the unscaled `_process` delta
+/// - `Time<Virtual>` relative speed mirrors `Engine.time_scale`
+/// - `Time<Virtual>` is paused while `SceneTree.paused` is true
+///
+/// Only Godot-side changes are mirrored, so pausing or rescaling `Time<Virtual>` from Bevy
+/// still works. Note that the `BevyApp` node only keeps updating while the tree is paused
+/// if its `process_mode` is set to `PROCESS_MODE_ALWAYS`.
+#[derive(Default)]
+pub struct GodotTimePlugin;
+
+impl Plugin for GodotTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProcessDelta>()
+            .init_non_send_resource::<SceneTreeRefImpl>()
+            .add_systems(First, sync_godot_time.before(TimeSystem));
+    }
+}
+
+#[derive(Default)]
+struct GodotTimeState {
+    time_scale: Option<f64>,
+    paused: Option<bool>,
+    last_ticks_usec: Option<u64>,
+}
+
+#[main_thread_system]
+fn sync_godot_time(
+    process_delta: Res<ProcessDelta>,
+    mut scene_tree: SceneTreeRef,
+    mut update_strategy: ResMut<TimeUpdateStrategy>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut state: Local<GodotTimeState>,
+) {
+    let time_scale = Engine::singleton().get_time_scale();
+    let ticks_usec = godot::classes::Time::singleton().get_ticks_usec();
+
+    // Godot scales the `_process` delta by `Engine.time_scale`, so undo that to get real time.
+    // With a time scale of zero the delta carries no information, so fall back to engine ticks.
+    let real_delta = if time_scale > 0.0 {
+        Duration::from_secs_f64(process_delta.delta_seconds / time_scale)
+    } else {
+        let last_ticks_usec = state.last_ticks_usec.unwrap_or(ticks_usec);
+        Duration::from_micros(ticks_usec.saturating_sub(last_ticks_usec))
+    };
+    state.last_ticks_usec = Some(ticks_usec);
+    *update_strategy = TimeUpdateStrategy::ManualDuration(real_delta);
+
+    if state.time_scale != Some(time_scale) {
+        virtual_time.set_relative_speed_f64(time_scale);
+        state.time_scale = Some(time_scale);
+    }
+
+    let paused = scene_tree.get().is_paused();
+    if state.paused != Some(paused) {
+        if paused {
+            virtual_time.pause();
+        } else {
+            virtual_time.unpause();
+        }
+        state.paused = Some(paused);
+    }
+}
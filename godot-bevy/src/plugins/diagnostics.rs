@@ -0,0 +1,391 @@
+use crate::prelude::main_thread_system;
+use bevy::app::{App, First, FixedMain, Last, Plugin, PostUpdate, PreUpdate, Update};
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, Diagnostics, DiagnosticsStore,
+    EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin, RegisterDiagnostic,
+};
+use bevy::ecs::resource::Resource;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::{NonSendMut, Res, ResMut};
+use bevy::ecs::world::World;
+use bevy::platform::time::Instant;
+use godot::builtin::{Callable, StringName};
+use godot::classes::Performance;
+use godot::classes::performance::Monitor;
+use godot::meta::ToGodot;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Exposes every Bevy [`Diagnostic`] as a Godot `Performance` custom monitor, so they show up in
+/// the debugger's Monitors tab next to the engine metrics. Diagnostics sampled from Godot by
+/// [`GodotPerformanceDiagnosticsPlugin`] are skipped, as Godot already shows them.
+///
+/// Also enables frame time and entity count diagnostics, plus godot-bevy's own: the time spent
+/// in each `BevyApp` callback and in the main schedules, and the number of transforms synced
+/// each frame.
+pub struct GodotDiagnosticsPlugin {
+    /// Monitors tab category the diagnostics are grouped under. Give each `BevyApp` its own
+    /// category when running multiple apps.
+    pub monitor_category: String,
+}
+
+impl Default for GodotDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            monitor_category: "Bevy".to_string(),
+        }
+    }
+}
+
+impl GodotDiagnosticsPlugin {
+    /// Time spent in `app.update()`, called from Godot's `_process`
+    pub const PROCESS_UPDATE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/process_update_time");
+    /// Time spent in the physics schedules, called from Godot's `_physics_process`
+    pub const PHYSICS_UPDATE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/physics_update_time");
+    /// Time spent in the `First` schedule
+    pub const FIRST_TIME: DiagnosticPath = DiagnosticPath::const_new("godot_bevy/first_time");
+    /// Time spent in the `PreUpdate` schedule
+    pub const PRE_UPDATE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/pre_update_time");
+    /// Time spent in the `Update` schedule
+    pub const UPDATE_TIME: DiagnosticPath = DiagnosticPath::const_new("godot_bevy/update_time");
+    /// Time spent in the `PostUpdate` schedule
+    pub const POST_UPDATE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/post_update_time");
+    /// Time spent in the `Last` schedule
+    pub const LAST_TIME: DiagnosticPath = DiagnosticPath::const_new("godot_bevy/last_time");
+    /// Time spent in each run of the `FixedMain` schedule, which runs zero or more times a frame
+    pub const FIXED_MAIN_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/fixed_main_time");
+    /// Number of transforms copied from Godot nodes into Bevy each frame
+    pub const TRANSFORMS_FROM_GODOT: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/transforms_from_godot");
+    /// Number of transforms written back to Godot nodes each frame
+    pub const TRANSFORMS_TO_GODOT: DiagnosticPath =
+        DiagnosticPath::const_new("godot_bevy/transforms_to_godot");
+}
+
+impl Plugin for GodotDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+
+        let schedules = [
+            (First.intern(), Self::FIRST_TIME),
+            (PreUpdate.intern(), Self::PRE_UPDATE_TIME),
+            (Update.intern(), Self::UPDATE_TIME),
+            (PostUpdate.intern(), Self::POST_UPDATE_TIME),
+            (Last.intern(), Self::LAST_TIME),
+            (FixedMain.intern(), Self::FIXED_MAIN_TIME),
+        ];
+        for (_, path) in &schedules {
+            app.register_diagnostic(Diagnostic::new(path.clone()).with_suffix("ms"));
+        }
+
+        app.register_diagnostic(Diagnostic::new(Self::PROCESS_UPDATE_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::PHYSICS_UPDATE_TIME).with_suffix("ms"))
+            .insert_resource(ScheduleDiagnostics(schedules.into_iter().collect()))
+            .register_diagnostic(Diagnostic::new(Self::TRANSFORMS_FROM_GODOT))
+            .register_diagnostic(Diagnostic::new(Self::TRANSFORMS_TO_GODOT))
+            .init_resource::<TransformSyncCounts>()
+            .add_systems(First, record_transform_sync_counts)
+            .insert_non_send_resource(GodotPerformanceMonitors {
+                category: self.monitor_category.clone(),
+                monitors: HashMap::new(),
+            })
+            .add_systems(Last, update_performance_monitors);
+    }
+}
+
+/// Transforms synced since the last frame. Inserted by [`GodotDiagnosticsPlugin`]; the
+/// transform sync systems, including ones generated with `add_transform_sync_systems!`, count
+/// into it when present.
+#[derive(Resource, Default, Debug)]
+pub struct TransformSyncCounts {
+    from_godot: usize,
+    to_godot: usize,
+}
+
+impl TransformSyncCounts {
+    /// Counts transforms copied from Godot nodes into Bevy
+    pub fn add_from_godot(&mut self, count: usize) {
+        self.from_godot += count;
+    }
+
+    /// Counts transforms written back to Godot nodes
+    pub fn add_to_godot(&mut self, count: usize) {
+        self.to_godot += count;
+    }
+}
+
+/// Records the transforms synced during the previous frame, as the sync systems run from
+/// `PreUpdate` to `Last`
+fn record_transform_sync_counts(
+    mut counts: ResMut<TransformSyncCounts>,
+    mut diagnostics: Diagnostics,
+) {
+    let TransformSyncCounts {
+        from_godot,
+        to_godot,
+    } = std::mem::take(&mut *counts);
+    diagnostics.add_measurement(&GodotDiagnosticsPlugin::TRANSFORMS_FROM_GODOT, || {
+        from_godot as f64
+    });
+    diagnostics.add_measurement(&GodotDiagnosticsPlugin::TRANSFORMS_TO_GODOT, || {
+        to_godot as f64
+    });
+}
+
+/// Records a `BevyApp` callback timing, if its diagnostic is registered
+pub(crate) fn record_update_time(world: &mut World, path: &DiagnosticPath, duration: Duration) {
+    if let Some(mut store) = world.get_resource_mut::<DiagnosticsStore>()
+        && let Some(diagnostic) = store.get_mut(path)
+        && diagnostic.is_enabled
+    {
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: duration.as_secs_f64() * 1000.0,
+        });
+    }
+}
+
+/// Diagnostics timing individual schedules, by schedule label
+#[derive(Resource)]
+pub(crate) struct ScheduleDiagnostics(HashMap<InternedScheduleLabel, DiagnosticPath>);
+
+/// Records the run time of a schedule, if it has a diagnostic. Called by the schedule runners
+/// `GodotBaseCorePlugin` installs.
+pub(crate) fn record_schedule_time(
+    world: &mut World,
+    label: InternedScheduleLabel,
+    duration: Duration,
+) {
+    let Some(path) = world
+        .get_resource::<ScheduleDiagnostics>()
+        .and_then(|schedules| schedules.0.get(&label))
+        .cloned()
+    else {
+        return;
+    };
+    record_update_time(world, &path, duration);
+}
+
+struct PerformanceMonitor {
+    id: StringName,
+    value: Rc<Cell<f64>>,
+}
+
+/// Custom monitors registered by this app, removed again when the app is dropped or rebuilt
+struct GodotPerformanceMonitors {
+    category: String,
+    monitors: HashMap<DiagnosticPath, PerformanceMonitor>,
+}
+
+impl GodotPerformanceMonitors {
+    fn register(&self, diagnostic: &Diagnostic) -> PerformanceMonitor {
+        // Godot splits monitor ids into `category/name`, so the name itself can't contain slashes
+        let mut name = diagnostic.path().as_str().replace('/', ".");
+        if !diagnostic.suffix.is_empty() {
+            name = format!("{name} ({})", diagnostic.suffix);
+        }
+        let mut performance = Performance::singleton();
+        let mut id = StringName::from(format!("{}/{name}", self.category).as_str());
+        // Monitors of a previous app are removed when it is dropped, so a taken id belongs to
+        // another `BevyApp` sharing the category. Keep its monitor and number ours.
+        if performance.has_custom_monitor(&id) {
+            let taken = id;
+            id = (2..)
+                .map(|n| StringName::from(format!("{} {n}/{name}", self.category).as_str()))
+                .find(|id| !performance.has_custom_monitor(id))
+                .expect("ran out of monitor ids");
+            warn!(
+                "Performance monitor '{taken}' is already registered, registering '{id}' \
+                 instead. Give each BevyApp its own GodotDiagnosticsPlugin::monitor_category."
+            );
+        }
+
+        let value = Rc::new(Cell::new(0.0));
+        let monitor_value = value.clone();
+        let callable =
+            Callable::from_local_fn(&name, move |_args| Ok(monitor_value.get().to_variant()));
+        performance.add_custom_monitor(&id, &callable);
+
+        PerformanceMonitor { id, value }
+    }
+}
+
+impl Drop for GodotPerformanceMonitors {
+    fn drop(&mut self) {
+        let mut performance = Performance::singleton();
+        for monitor in self.monitors.values() {
+            if performance.has_custom_monitor(&monitor.id) {
+                performance.remove_custom_monitor(&monitor.id);
+            }
+        }
+    }
+}
+
+#[main_thread_system]
+fn update_performance_monitors(
+    store: Res<DiagnosticsStore>,
+    mut monitors: NonSendMut<GodotPerformanceMonitors>,
+) {
+    for diagnostic in store.iter().filter(|diagnostic| {
+        diagnostic.is_enabled
+            && !diagnostic
+                .path()
+                .as_str()
+                .starts_with(GodotPerformanceDiagnosticsPlugin::NAMESPACE)
+    }) {
+        let Some(value) = diagnostic.smoothed() else {
+            continue;
+        };
+
+        if !monitors.monitors.contains_key(diagnostic.path()) {
+            let monitor = monitors.register(diagnostic);
+            monitors.monitors.insert(diagnostic.path().clone(), monitor);
+        }
+        monitors.monitors[diagnostic.path()].value.set(value);
+    }
+}
+
+/// Samples Godot's `Performance` monitors into Bevy's [`DiagnosticsStore`] every frame, under
+/// the `godot/` namespace, so Bevy diagnostic overlays and tooling can read engine metrics too.
+///
+/// Times are reported in milliseconds and memory in bytes.
+#[derive(Default)]
+pub struct GodotPerformanceDiagnosticsPlugin;
+
+impl GodotPerformanceDiagnosticsPlugin {
+    /// Prefix shared by all diagnostics sampled from Godot
+    pub const NAMESPACE: &'static str = "godot/";
+
+    pub const FPS: DiagnosticPath = DiagnosticPath::const_new("godot/fps");
+    pub const PROCESS_TIME: DiagnosticPath = DiagnosticPath::const_new("godot/process_time");
+    pub const PHYSICS_PROCESS_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("godot/physics_process_time");
+    pub const STATIC_MEMORY: DiagnosticPath = DiagnosticPath::const_new("godot/static_memory");
+    pub const VIDEO_MEMORY: DiagnosticPath = DiagnosticPath::const_new("godot/video_memory");
+    pub const OBJECT_COUNT: DiagnosticPath = DiagnosticPath::const_new("godot/object_count");
+    pub const RESOURCE_COUNT: DiagnosticPath = DiagnosticPath::const_new("godot/resource_count");
+    pub const NODE_COUNT: DiagnosticPath = DiagnosticPath::const_new("godot/node_count");
+    pub const ORPHAN_NODE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("godot/orphan_node_count");
+    pub const DRAW_CALLS: DiagnosticPath = DiagnosticPath::const_new("godot/draw_calls");
+    pub const OBJECTS_DRAWN: DiagnosticPath = DiagnosticPath::const_new("godot/objects_drawn");
+    pub const PRIMITIVES_DRAWN: DiagnosticPath =
+        DiagnosticPath::const_new("godot/primitives_drawn");
+}
+
+struct SampledMonitor {
+    path: DiagnosticPath,
+    monitor: Monitor,
+    suffix: &'static str,
+    scale: f64,
+}
+
+const SAMPLED_MONITORS: [SampledMonitor; 12] = [
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::FPS,
+        monitor: Monitor::TIME_FPS,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::PROCESS_TIME,
+        monitor: Monitor::TIME_PROCESS,
+        suffix: "ms",
+        scale: 1000.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::PHYSICS_PROCESS_TIME,
+        monitor: Monitor::TIME_PHYSICS_PROCESS,
+        suffix: "ms",
+        scale: 1000.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::STATIC_MEMORY,
+        monitor: Monitor::MEMORY_STATIC,
+        suffix: "B",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::VIDEO_MEMORY,
+        monitor: Monitor::RENDER_VIDEO_MEM_USED,
+        suffix: "B",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::OBJECT_COUNT,
+        monitor: Monitor::OBJECT_COUNT,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::RESOURCE_COUNT,
+        monitor: Monitor::OBJECT_RESOURCE_COUNT,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::NODE_COUNT,
+        monitor: Monitor::OBJECT_NODE_COUNT,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::ORPHAN_NODE_COUNT,
+        monitor: Monitor::OBJECT_ORPHAN_NODE_COUNT,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::DRAW_CALLS,
+        monitor: Monitor::RENDER_TOTAL_DRAW_CALLS_IN_FRAME,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::OBJECTS_DRAWN,
+        monitor: Monitor::RENDER_TOTAL_OBJECTS_IN_FRAME,
+        suffix: "",
+        scale: 1.0,
+    },
+    SampledMonitor {
+        path: GodotPerformanceDiagnosticsPlugin::PRIMITIVES_DRAWN,
+        monitor: Monitor::RENDER_TOTAL_PRIMITIVES_IN_FRAME,
+        suffix: "",
+        scale: 1.0,
+    },
+];
+
+impl Plugin for GodotPerformanceDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for sampled in &SAMPLED_MONITORS {
+            app.register_diagnostic(
+                Diagnostic::new(sampled.path.clone()).with_suffix(sampled.suffix),
+            );
+        }
+
+        app.add_systems(PreUpdate, sample_godot_performance);
+    }
+}
+
+#[main_thread_system]
+fn sample_godot_performance(mut diagnostics: Diagnostics) {
+    let performance = Performance::singleton();
+    for sampled in &SAMPLED_MONITORS {
+        diagnostics.add_measurement(&sampled.path, || {
+            performance.get_monitor(sampled.monitor) * sampled.scale
+        });
+    }
+}
@@ -74,18 +74,20 @@ impl Plugin for GodotBevyLogPlugin {
             timestamp_format: self.timestamp_format.clone(),
         };
 
+        // Only the first app installs the global subscriber, later apps (multiple `BevyApp`
+        // nodes, or an app rebuilt after a panic) log through it
         #[cfg(feature = "trace_tracy")]
-        tracing_subscriber::registry()
+        let _ = tracing_subscriber::registry()
             .with(godot_proxy_layer)
             .with(filter_layer)
             .with(tracing_tracy::TracyLayer::default())
-            .init();
+            .try_init();
 
         #[cfg(not(feature = "trace_tracy"))]
-        tracing_subscriber::registry()
+        let _ = tracing_subscriber::registry()
             .with(godot_proxy_layer)
             .with(filter_layer)
-            .init();
+            .try_init();
     }
 }
 
@@ -6,26 +6,38 @@ pub mod assets;
 pub mod audio;
 pub mod collisions;
 pub mod core;
+pub mod diagnostics;
 #[cfg(feature = "godot_bevy_log")]
 pub mod godot_bevy_logger;
 pub mod input;
 pub mod packed_scene;
+pub mod process_mode;
+#[cfg(feature = "bevy_state")]
+pub mod scene_state;
 pub mod scene_tree;
 pub mod signals;
 pub mod transforms;
+pub mod visibility;
 
 // Re-export all plugins for convenience
 pub use assets::GodotAssetsPlugin;
 pub use audio::GodotAudioPlugin;
 pub use collisions::GodotCollisionsPlugin;
-pub use core::GodotBaseCorePlugin;
+pub use core::{GodotBaseCorePlugin, GodotLifecyclePlugin, GodotTimePlugin};
+pub use diagnostics::{
+    GodotDiagnosticsPlugin, GodotPerformanceDiagnosticsPlugin, TransformSyncCounts,
+};
 #[cfg(feature = "godot_bevy_log")]
 pub use godot_bevy_logger::GodotBevyLogPlugin;
 pub use input::{BevyInputBridgePlugin, GodotInputEventPlugin};
 pub use packed_scene::GodotPackedScenePlugin;
+pub use process_mode::GodotProcessModePlugin;
+#[cfg(feature = "bevy_state")]
+pub use scene_state::GodotSceneStatePlugin;
 pub use scene_tree::GodotSceneTreePlugin;
 pub use signals::GodotSignalsPlugin;
 pub use transforms::GodotTransformSyncPlugin;
+pub use visibility::GodotVisibilityPlugin;
 
 // Re-export for backwards compatibility
 #[deprecated(note = "Use GodotInputEventPlugin instead")]
@@ -33,10 +45,23 @@ pub use input::GodotInputEventPlugin as GodotInputPlugin;
 
 plugin_group! {
     /// Minimal core functionality required for Godot-Bevy integration.
-    /// This includes scene tree management
+    /// This includes scene tree management, Godot-driven time and the app lifecycle bridge
     pub struct GodotCorePlugins {
         :GodotBaseCorePlugin,
         :GodotSceneTreePlugin,
+        :GodotTimePlugin,
+        :GodotLifecyclePlugin,
+    }
+}
+
+plugin_group! {
+    /// Restricted plugin set used by the `#[bevy_app(editor)]` app while authoring scenes:
+    /// scene tree mirroring and transform sync, without input, audio or the app lifecycle bridge
+    pub struct GodotEditorPlugins {
+        :GodotBaseCorePlugin,
+        :GodotSceneTreePlugin,
+        :GodotTimePlugin,
+        :GodotTransformSyncPlugin,
     }
 }
 
@@ -1,4 +1,4 @@
-use super::scene_tree::SceneTreeRef;
+use super::scene_tree::{SceneTreeConfig, SceneTreeRef};
 use crate::plugins::assets::GodotResource;
 use crate::plugins::transforms::IntoGodotTransform2D;
 use crate::prelude::main_thread_system;
@@ -10,7 +10,7 @@ use bevy::{
         component::Component,
         entity::Entity,
         query::Without,
-        system::{Commands, Query, ResMut},
+        system::{Commands, Query, Res, ResMut},
     },
     log::tracing,
     transform::components::Transform,
@@ -84,6 +84,7 @@ fn spawn_scene(
     mut new_scenes: Query<(&mut GodotScene, Entity, Option<&Transform>), Without<GodotNodeHandle>>,
     mut scene_tree: SceneTreeRef,
     mut assets: ResMut<Assets<GodotResource>>,
+    config: Option<Res<SceneTreeConfig>>,
 ) {
     for (mut scene, ent, transform) in new_scenes.iter_mut() {
         let packed_scene = match &scene.resource {
@@ -131,7 +132,12 @@ fn spawn_scene(
                 parent.add_child(&instance);
             }
             None => {
-                scene_tree.get().get_root().unwrap().add_child(&instance);
+                // Scoped apps spawn under their own subtree so the scene gets mirrored
+                let mut parent = config
+                    .as_ref()
+                    .and_then(|config| config.scene_root())
+                    .unwrap_or_else(|| scene_tree.get().get_root().unwrap().upcast());
+                parent.add_child(&instance);
             }
         }
 
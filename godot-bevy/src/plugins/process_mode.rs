@@ -0,0 +1,194 @@
+use crate::interop::GodotNodeHandle;
+use crate::plugins::core::AppSceneTreeExt;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::plugins::scene_tree::plugin::SceneTreeRefImpl;
+use crate::prelude::main_thread_system;
+use bevy::app::{App, Plugin, PreUpdate};
+use bevy::ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::Entity,
+    resource::Resource,
+    system::{Commands, Local, Query, Res, ResMut},
+};
+use godot::classes::{Node, node::ProcessMode};
+use godot::obj::{Gd, InstanceId};
+use std::collections::HashMap;
+
+/// Effective `process_mode` of a mirrored node, with `PROCESS_MODE_INHERIT` resolved through its
+/// ancestors the way Godot does. The scene tree root is `Pausable`.
+///
+/// Added to every scene tree entity by [`GodotProcessModePlugin`]. Combine it with
+/// [`GodotPauseState`] to make systems follow Godot's pause semantics per entity:
+///
+/// ```ignore
+/// fn animate(pause: Res<GodotPauseState>, mut sprites: Query<(&GodotProcessMode, &mut Frame)>) {
+///     for (mode, mut frame) in &mut sprites {
+///         if pause.is_active(*mode) {
+///             frame.advance();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GodotProcessMode {
+    /// Processes only while the tree is not paused
+    Pausable,
+    /// Processes only while the tree is paused
+    WhenPaused,
+    /// Always processes
+    Always,
+    /// Never processes
+    Disabled,
+}
+
+impl GodotProcessMode {
+    /// Whether a node with this mode processes while the tree is (or isn't) paused
+    pub fn is_active(self, tree_paused: bool) -> bool {
+        match self {
+            Self::Pausable => !tree_paused,
+            Self::WhenPaused => tree_paused,
+            Self::Always => true,
+            Self::Disabled => false,
+        }
+    }
+
+    /// Resolves the mode of `node`, walking up its ancestors while it inherits
+    pub fn of(node: &Gd<Node>) -> Self {
+        Self::resolve(node, &mut HashMap::new())
+    }
+
+    fn resolve(node: &Gd<Node>, resolved: &mut HashMap<InstanceId, Self>) -> Self {
+        if let Some(mode) = resolved.get(&node.instance_id()) {
+            return *mode;
+        }
+
+        let mode = match node.get_process_mode() {
+            ProcessMode::PAUSABLE => Self::Pausable,
+            ProcessMode::WHEN_PAUSED => Self::WhenPaused,
+            ProcessMode::ALWAYS => Self::Always,
+            ProcessMode::DISABLED => Self::Disabled,
+            _ => node
+                .get_parent()
+                .map_or(Self::Pausable, |parent| Self::resolve(&parent, resolved)),
+        };
+        resolved.insert(node.instance_id(), mode);
+        mode
+    }
+}
+
+/// Marker for entities whose node keeps processing while the tree is paused, i.e. with a
+/// [`GodotProcessMode`] of `WhenPaused` or `Always`. Use it as a query filter in systems that
+/// should only touch pause menus and other UI while the game is paused:
+///
+/// ```ignore
+/// app.add_systems(Update, animate_pause_menu.run_if(tree_paused));
+///
+/// fn animate_pause_menu(menus: Query<&GodotNodeHandle, With<ActiveWhenTreePaused>>) {
+///     // ...
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ActiveWhenTreePaused;
+
+/// Whether Godot's `SceneTree` is paused, readable from any system. Updated in `PreUpdate`.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GodotPauseState {
+    pub paused: bool,
+}
+
+impl GodotPauseState {
+    /// Whether an entity with this mode should be processed this frame
+    pub fn is_active(&self, mode: GodotProcessMode) -> bool {
+        mode.is_active(self.paused)
+    }
+}
+
+/// Run condition: the scene tree is paused
+pub fn tree_paused(pause: Res<GodotPauseState>) -> bool {
+    pause.paused
+}
+
+/// Mirrors node `process_mode` into [`GodotProcessMode`] components and the tree's pause state
+/// into [`GodotPauseState`].
+///
+/// Godot has no signal for `process_mode` changes, so the mode of every mirrored node is checked
+/// once per frame. Note that the `BevyApp` node itself needs `PROCESS_MODE_ALWAYS` for any system
+/// to run while the tree is paused.
+#[derive(Default)]
+pub struct GodotProcessModePlugin;
+
+impl Plugin for GodotProcessModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GodotPauseState>()
+            .init_non_send_resource::<SceneTreeRefImpl>()
+            .register_scene_tree_component_with_init::<GodotProcessMode, _>(|entity, handle| {
+                let Ok(node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+                    return;
+                };
+                let mode = GodotProcessMode::of(&node);
+                entity.insert(mode);
+                if mode.is_active(true) {
+                    entity.insert(ActiveWhenTreePaused);
+                }
+            })
+            .add_systems(PreUpdate, (sync_pause_state, sync_process_modes));
+    }
+}
+
+#[main_thread_system]
+fn sync_pause_state(mut scene_tree: SceneTreeRef, mut pause: ResMut<GodotPauseState>) {
+    let paused = scene_tree.get().is_paused();
+    pause.set_if_neq(GodotPauseState { paused });
+}
+
+#[main_thread_system]
+fn sync_process_modes(
+    mut commands: Commands,
+    mut nodes: Query<(Entity, &GodotNodeHandle, &mut GodotProcessMode)>,
+    mut resolved: Local<HashMap<InstanceId, GodotProcessMode>>,
+) {
+    // Shared across nodes so each ancestor is only resolved once per frame
+    resolved.clear();
+
+    for (entity, handle, mut mode) in nodes.iter_mut() {
+        let Ok(node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+            continue;
+        };
+        let current = GodotProcessMode::resolve(&node, &mut resolved);
+        if *mode == current {
+            continue;
+        }
+
+        match (mode.is_active(true), current.is_active(true)) {
+            (false, true) => {
+                commands.entity(entity).insert(ActiveWhenTreePaused);
+            }
+            (true, false) => {
+                commands.entity(entity).remove::<ActiveWhenTreePaused>();
+            }
+            _ => {}
+        }
+        *mode = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_godot_pause_semantics() {
+        let modes = [
+            GodotProcessMode::Pausable,
+            GodotProcessMode::WhenPaused,
+            GodotProcessMode::Always,
+            GodotProcessMode::Disabled,
+        ];
+        let running: Vec<_> = modes.iter().map(|mode| mode.is_active(false)).collect();
+        let paused: Vec<_> = modes.iter().map(|mode| mode.is_active(true)).collect();
+
+        assert_eq!(running, [true, false, true, false]);
+        assert_eq!(paused, [false, true, true, false]);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::interop::GodotEntityIndex;
+use crate::plugins::assets::GodotResource;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::prelude::main_thread_system;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::asset::{AssetServer, Assets, Handle, LoadState};
+use bevy::ecs::event::{Event, EventWriter};
+use bevy::ecs::system::{Res, ResMut};
+use bevy::prelude::Resource;
+use bevy::state::state::{OnEnter, States};
+use godot::classes::PackedScene;
+use godot::global::{Error, godot_error};
+use godot::obj::InstanceId;
+use std::collections::HashMap;
+
+/// Switches Godot's current scene when a Bevy [`States`] value is entered.
+///
+/// Each mapped state value is paired with a `.tscn` path. The scenes are preloaded through
+/// `GodotAssetsPlugin` at startup, swapped in with `SceneTree.change_scene_to_packed` on
+/// `OnEnter`, and a [`SceneTransitionComplete`] event is written once the new scene's entities
+/// are mirrored. If a scene fails to load or instantiate, an error is logged and the current
+/// scene is kept. Requires `GodotAssetsPlugin` and `StatesPlugin`.
+///
+/// ```ignore
+/// app.add_plugins(
+///     GodotSceneStatePlugin::new()
+///         .with_scene(Level::One, "scenes/levels/level_1.tscn")
+///         .with_scene(Level::Two, "scenes/levels/level_2.tscn"),
+/// );
+/// ```
+pub struct GodotSceneStatePlugin<S: States> {
+    scenes: HashMap<S, String>,
+}
+
+impl<S: States> Default for GodotSceneStatePlugin<S> {
+    fn default() -> Self {
+        Self {
+            scenes: HashMap::new(),
+        }
+    }
+}
+
+impl<S: States> GodotSceneStatePlugin<S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Changes to the scene at `path` whenever `state` is entered
+    pub fn with_scene(mut self, state: S, path: impl Into<String>) -> Self {
+        self.scenes.insert(state, path.into());
+        self
+    }
+}
+
+/// Written once the scene mapped to `state` is current and its nodes have been mirrored as
+/// entities, so systems can safely query the new level
+#[derive(Event, Debug, Clone)]
+pub struct SceneTransitionComplete<S: States> {
+    pub state: S,
+}
+
+/// Scene paths for each state, and their preloaded handles
+#[derive(Resource)]
+struct SceneStateScenes<S: States> {
+    paths: HashMap<S, String>,
+    handles: HashMap<S, Handle<GodotResource>>,
+}
+
+#[derive(Resource)]
+struct SceneStateTransition<S: States> {
+    /// State whose scene should become current, once its PackedScene is loaded
+    requested: Option<S>,
+    /// Scene change already issued, waiting for the new scene to be mirrored
+    changing: Option<ChangingScene<S>>,
+}
+
+impl<S: States> Default for SceneStateTransition<S> {
+    fn default() -> Self {
+        Self {
+            requested: None,
+            changing: None,
+        }
+    }
+}
+
+struct ChangingScene<S> {
+    state: S,
+    scene_path: String,
+    previous_scene: Option<InstanceId>,
+}
+
+impl<S: States> Plugin for GodotSceneStatePlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SceneStateScenes::<S> {
+            paths: self.scenes.clone(),
+            handles: HashMap::new(),
+        })
+        .init_resource::<SceneStateTransition<S>>()
+        .add_event::<SceneTransitionComplete<S>>()
+        .add_systems(Startup, preload_scenes::<S>)
+        .add_systems(
+            Update,
+            (
+                change_scene_when_loaded::<S>,
+                complete_scene_transition::<S>,
+            ),
+        );
+
+        for state in self.scenes.keys() {
+            let state = state.clone();
+            app.add_systems(
+                OnEnter(state.clone()),
+                move |mut transition: ResMut<SceneStateTransition<S>>| {
+                    transition.requested = Some(state.clone());
+                },
+            );
+        }
+    }
+}
+
+fn preload_scenes<S: States>(
+    mut scenes: ResMut<SceneStateScenes<S>>,
+    asset_server: Res<AssetServer>,
+) {
+    let handles = scenes
+        .paths
+        .iter()
+        .map(|(state, path)| (state.clone(), asset_server.load(path.clone())))
+        .collect();
+    scenes.handles = handles;
+}
+
+#[main_thread_system]
+fn change_scene_when_loaded<S: States>(
+    mut scene_tree: SceneTreeRef,
+    scenes: Res<SceneStateScenes<S>>,
+    mut transition: ResMut<SceneStateTransition<S>>,
+    mut assets: ResMut<Assets<GodotResource>>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(state) = transition.requested.clone() else {
+        return;
+    };
+
+    let Some(handle) = scenes.handles.get(&state) else {
+        // OnEnter ran before Startup, e.g. for the initial state; try again next frame
+        return;
+    };
+
+    let Some(resource) = assets.get_mut(handle) else {
+        // Not loaded yet, try again next frame, unless it never will be
+        if let Some(LoadState::Failed(error)) = asset_server.get_load_state(handle) {
+            transition.requested = None;
+            godot_error!(
+                "Failed to load the scene {} for state {state:?}, keeping the current scene: {error}",
+                scenes.paths[&state]
+            );
+        }
+        return;
+    };
+
+    transition.requested = None;
+    let Some(packed_scene) = resource.try_cast::<PackedScene>() else {
+        godot_error!(
+            "Scene {} for state {state:?} is not a PackedScene, keeping the current scene",
+            scenes.paths[&state]
+        );
+        return;
+    };
+
+    let mut scene_tree = scene_tree.get();
+    let previous_scene = scene_tree
+        .get_current_scene()
+        .map(|scene| scene.instance_id());
+    // The current scene is left in place when the new one can't be instantiated
+    let result = scene_tree.change_scene_to_packed(&packed_scene);
+    if result != Error::OK {
+        godot_error!(
+            "Failed to change to the scene {} for state {state:?}, keeping the current scene: \
+             {result:?}",
+            scenes.paths[&state]
+        );
+        return;
+    }
+
+    transition.changing = Some(ChangingScene {
+        state,
+        scene_path: packed_scene.get_path().to_string(),
+        previous_scene,
+    });
+}
+
+#[main_thread_system]
+fn complete_scene_transition<S: States>(
+    mut scene_tree: SceneTreeRef,
+    mut transition: ResMut<SceneStateTransition<S>>,
+    entity_index: Res<GodotEntityIndex>,
+    mut complete_events: EventWriter<SceneTransitionComplete<S>>,
+) {
+    let Some(changing) = &transition.changing else {
+        return;
+    };
+
+    // Godot swaps the current scene at the end of the frame, and its nodes are mirrored in
+    // `First` of the frame after that
+    let Some(current_scene) = scene_tree.get().get_current_scene() else {
+        return;
+    };
+    let current_id = current_scene.instance_id();
+    if Some(current_id) == changing.previous_scene
+        || current_scene.get_scene_file_path().to_string() != changing.scene_path
+        || entity_index.entity_for(current_id).is_none()
+    {
+        return;
+    }
+
+    let state = changing.state.clone();
+    transition.changing = None;
+    complete_events.write(SceneTransitionComplete { state });
+}
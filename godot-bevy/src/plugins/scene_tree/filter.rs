@@ -0,0 +1,65 @@
+use godot::classes::Node;
+use godot::obj::Gd;
+
+/// Node metadata key that excludes a node, and everything below it, from mirroring when set to `true`
+pub const BEVY_IGNORE_META: &str = "bevy_ignore";
+
+/// Nodes that should not be mirrored into the ECS.
+///
+/// A node matching any rule is skipped together with all of its descendants, both during the
+/// initial traversal and for nodes added later. Use it to keep large UI trees or decorative
+/// nodes you never query out of the world. Nodes can also opt out individually by setting the
+/// [`BEVY_IGNORE_META`] metadata to `true`.
+///
+/// ```ignore
+/// #[bevy_app]
+/// fn build_app(app: &mut App) {
+///     app.world_mut().resource_mut::<SceneTreeConfig>().filter = SceneTreeFilter::default()
+///         .ignore_group("ui")
+///         .ignore_class("CPUParticles2D");
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SceneTreeFilter {
+    pub groups: Vec<String>,
+    pub meta_keys: Vec<String>,
+    pub classes: Vec<String>,
+}
+
+impl SceneTreeFilter {
+    /// Ignore nodes in the given group
+    pub fn ignore_group(mut self, group: &str) -> Self {
+        self.groups.push(group.into());
+        self
+    }
+
+    /// Ignore nodes that have metadata under the given key, whatever its value
+    pub fn ignore_meta(mut self, key: &str) -> Self {
+        self.meta_keys.push(key.into());
+        self
+    }
+
+    /// Ignore nodes of the given class, including classes inheriting from it
+    pub fn ignore_class(mut self, class: &str) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    /// Whether this node itself is excluded, not considering its ancestors
+    pub(crate) fn ignores(&self, node: &Gd<Node>) -> bool {
+        (node.has_meta(BEVY_IGNORE_META)
+            && node
+                .get_meta(BEVY_IGNORE_META)
+                .try_to::<bool>()
+                .unwrap_or(false))
+            || self
+                .groups
+                .iter()
+                .any(|group| node.is_in_group(group.as_str()))
+            || self.meta_keys.iter().any(|key| node.has_meta(key.as_str()))
+            || self
+                .classes
+                .iter()
+                .any(|class| node.is_class(class.as_str()))
+    }
+}
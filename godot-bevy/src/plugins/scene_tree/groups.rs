@@ -0,0 +1,98 @@
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+use bevy::ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    query::With,
+    system::Query,
+};
+use godot::classes::Node;
+use godot::obj::{Gd, Inherits};
+
+#[derive(Component, Debug)]
+pub struct Groups {
+    groups: Vec<String>,
+}
+
+impl Groups {
+    pub fn is(&self, group_name: &str) -> bool {
+        self.groups.iter().any(|name| name == group_name)
+    }
+
+    /// Names of all groups the node belongs to
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.groups.iter().map(String::as_str)
+    }
+}
+
+impl<T: Inherits<Node>> From<&Gd<T>> for Groups {
+    fn from(node: &Gd<T>) -> Self {
+        Groups {
+            groups: node
+                .clone()
+                .upcast::<Node>()
+                .get_groups()
+                .iter_shared()
+                .map(|variant| variant.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Marks an entity whose node's groups are polled each frame, keeping its `Groups` component
+/// up to date and sending [`GroupAdded`]/[`GroupRemoved`] events. Insert it on entities whose
+/// groups change at runtime, or set `SceneTreeConfig::sync_groups` to track every mirrored node.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct TrackGroups;
+
+/// Sent when a mirrored node is added to a group after its entity was created, e.g. through
+/// `add_to_group`
+#[derive(Debug, Clone, Event)]
+pub struct GroupAdded {
+    pub entity: Entity,
+    pub group: String,
+}
+
+/// Sent when a mirrored node is removed from a group, e.g. through `remove_from_group`
+#[derive(Debug, Clone, Event)]
+pub struct GroupRemoved {
+    pub entity: Entity,
+    pub group: String,
+}
+
+/// Godot has no signal for group changes, so the groups of every node marked with
+/// [`TrackGroups`] are compared against their `Groups` component once per frame
+#[main_thread_system]
+pub(crate) fn sync_groups(
+    mut nodes: Query<(Entity, &GodotNodeHandle, &mut Groups), With<TrackGroups>>,
+    mut group_added: EventWriter<GroupAdded>,
+    mut group_removed: EventWriter<GroupRemoved>,
+) {
+    for (entity, handle, mut groups) in nodes.iter_mut() {
+        let Ok(node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+            continue;
+        };
+        let current = Groups::from(&node);
+
+        // Only touch the component when something changed, so `Changed<Groups>` stays meaningful
+        if current.groups == groups.groups {
+            continue;
+        }
+
+        for group in current.groups.iter().filter(|group| !groups.is(group)) {
+            group_added.write(GroupAdded {
+                entity,
+                group: group.clone(),
+            });
+        }
+        for group in groups.groups.iter().filter(|group| !current.is(group)) {
+            group_removed.write(GroupRemoved {
+                entity,
+                group: group.clone(),
+            });
+        }
+
+        *groups = current;
+    }
+}
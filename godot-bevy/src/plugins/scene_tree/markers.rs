@@ -0,0 +1,81 @@
+use crate::interop::{
+    CanvasItemMarker, ControlMarker, GodotNodeHandle, Node2DMarker, Node3DMarker, NodeMarker,
+};
+use bevy::ecs::{component::Component, system::EntityCommands};
+use godot::classes::{Control, Node2D, Node3D};
+
+/// Which node type marker components (`Sprite2DMarker`, `Node3DMarker`, ...) are added to
+/// entities mirrored from the scene tree.
+///
+/// Figuring out the markers of a node takes a cascade of casts when the optimized watcher isn't
+/// available, and each marker is a component insert. For scenes with tens of thousands of nodes
+/// that adds up, so markers can be narrowed down or disabled, and [`GodotClass`] used instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum MarkerPolicy {
+    /// Markers for the node's class and all of its ancestors
+    #[default]
+    All,
+    /// Only the markers of the main branches: `NodeMarker`, `Node2DMarker`, `Node3DMarker`,
+    /// `ControlMarker` and `CanvasItemMarker`
+    BaseOnly,
+    /// All markers for nodes of these classes (or classes inheriting from them), base markers
+    /// for every other node
+    Classes(Vec<String>),
+    /// No markers, except `Node2DMarker` and `Node3DMarker` which transform syncing relies on
+    None,
+}
+
+impl MarkerPolicy {
+    /// All markers, only for nodes of the given classes and their subclasses
+    pub fn classes<I, S>(classes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Classes(classes.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Exact Godot class of the node, e.g. `"Sprite2D"` or the name of your own `GodotClass`.
+/// Added to every entity mirrored from the scene tree, whatever the [`MarkerPolicy`].
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GodotClass(pub String);
+
+impl GodotClass {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the node is exactly of this class, ignoring inheritance
+    pub fn is(&self, class: &str) -> bool {
+        self.0 == class
+    }
+}
+
+/// Adds the markers of [`MarkerPolicy::None`], with at most two casts
+pub(crate) fn add_transform_node_type_markers(
+    entity_commands: &mut EntityCommands,
+    node: &mut GodotNodeHandle,
+) {
+    if node.try_get::<Node3D>().is_some() {
+        entity_commands.insert(Node3DMarker);
+    } else if node.try_get::<Node2D>().is_some() {
+        entity_commands.insert(Node2DMarker);
+    }
+}
+
+/// Adds the markers of [`MarkerPolicy::BaseOnly`], with at most three casts
+pub(crate) fn add_base_node_type_markers(
+    entity_commands: &mut EntityCommands,
+    node: &mut GodotNodeHandle,
+) {
+    entity_commands.insert(NodeMarker);
+
+    if node.try_get::<Node3D>().is_some() {
+        entity_commands.insert(Node3DMarker);
+    } else if node.try_get::<Node2D>().is_some() {
+        entity_commands.insert((Node2DMarker, CanvasItemMarker));
+    } else if node.try_get::<Control>().is_some() {
+        entity_commands.insert((ControlMarker, CanvasItemMarker));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::interop::GodotNodeHandle;
+use crate::prelude::main_thread_system;
+use bevy::ecs::{
+    change_detection::DetectChangesMut,
+    component::{Component, Mutable},
+    entity::Entity,
+    query::{Has, With},
+    resource::Resource,
+    system::{Commands, Query, Res},
+};
+use godot::builtin::Variant;
+use godot::classes::Node;
+use godot::obj::Gd;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Converts a node's metadata value into a component, `None` if the value has the wrong type
+pub type MetaConverter<C> = dyn Fn(&Variant) -> Option<C> + Send + Sync;
+
+/// Metadata key and conversion for a component registered with
+/// [`register_meta_component`](crate::plugins::core::AppSceneTreeExt::register_meta_component)
+#[derive(Resource)]
+pub(crate) struct MetaComponentSource<C> {
+    pub key: String,
+    pub convert: Arc<MetaConverter<C>>,
+}
+
+impl<C> Clone for MetaComponentSource<C> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            convert: self.convert.clone(),
+        }
+    }
+}
+
+impl<C> MetaComponentSource<C> {
+    /// Reads the component from the node's metadata, `None` if it has no such key
+    /// or its value could not be converted
+    pub fn read(&self, node: &Gd<Node>) -> Option<C> {
+        if !node.has_meta(self.key.as_str()) {
+            return None;
+        }
+
+        let component = (self.convert)(&node.get_meta(self.key.as_str()));
+        if component.is_none() {
+            warn!(
+                "Metadata '{}' of node {} could not be converted to {}",
+                self.key,
+                node.get_path(),
+                std::any::type_name::<C>()
+            );
+        }
+        component
+    }
+}
+
+/// Marks an entity whose node's metadata is read again each frame, updating the components
+/// registered with
+/// [`register_meta_component`](crate::plugins::core::AppSceneTreeExt::register_meta_component).
+/// Insert it on entities whose metadata changes at runtime, or set `SceneTreeConfig::sync_meta`
+/// to track every mirrored node.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct TrackMeta;
+
+/// Marks a `C` that was inserted from node metadata. Metadata syncing only updates and removes
+/// components it inserted, leaving ones added by gameplay code alone.
+#[derive(Component)]
+pub(crate) struct FromMeta<C: Component>(PhantomData<fn() -> C>);
+
+impl<C: Component> Default for FromMeta<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Godot has no signal for metadata changes, so the metadata of every node marked with
+/// [`TrackMeta`] is read again each frame
+#[main_thread_system]
+pub(crate) fn sync_meta_component<C: Component<Mutability = Mutable> + PartialEq>(
+    mut commands: Commands,
+    source: Res<MetaComponentSource<C>>,
+    mut nodes: Query<(Entity, &GodotNodeHandle, Option<&mut C>, Has<FromMeta<C>>), With<TrackMeta>>,
+) {
+    for (entity, handle, component, from_meta) in nodes.iter_mut() {
+        let Ok(node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+            continue;
+        };
+
+        if !node.has_meta(source.key.as_str()) {
+            if from_meta {
+                commands.entity(entity).remove::<(C, FromMeta<C>)>();
+            }
+            continue;
+        }
+
+        // A value of the wrong type keeps the last valid component
+        let Some(value) = (source.convert)(&node.get_meta(source.key.as_str())) else {
+            continue;
+        };
+        match component {
+            Some(mut component) if from_meta => {
+                component.set_if_neq(value);
+            }
+            Some(_) => {}
+            None => {
+                commands
+                    .entity(entity)
+                    .insert((value, FromMeta::<C>::default()));
+            }
+        }
+    }
+}
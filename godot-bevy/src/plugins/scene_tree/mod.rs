@@ -1,13 +1,25 @@
 pub mod autosync;
+pub mod filter;
+pub mod groups;
+pub mod markers;
+pub mod meta;
 pub mod node_type_checking_generated;
+pub mod nodes;
 pub mod plugin;
+pub mod scene_instance;
 
 // Re-export main components
 pub use autosync::{
     AutoSyncBundleRegistry, BundleCreatorFn, register_all_autosync_bundles,
     try_add_bundles_for_node,
 };
+pub use filter::{BEVY_IGNORE_META, SceneTreeFilter};
+pub use groups::{GroupAdded, GroupRemoved, Groups, TrackGroups};
+pub use markers::{GodotClass, MarkerPolicy};
+pub use meta::{MetaConverter, TrackMeta};
+pub use nodes::GodotNodes;
 pub use plugin::{
-    GodotSceneTreePlugin, Groups, SceneTreeConfig, SceneTreeEvent, SceneTreeEventReader,
+    GodotNodeReady, GodotSceneTreePlugin, SceneTreeConfig, SceneTreeEvent, SceneTreeEventReader,
     SceneTreeEventType, SceneTreeRef,
 };
+pub use scene_instance::SceneInstance;
@@ -0,0 +1,94 @@
+use super::plugin::{SceneTreeConfig, SceneTreeRef};
+use crate::interop::{GodotEntityIndex, GodotNodeHandle};
+use crate::node_tree_view::{find_node_by_pattern, find_nodes_by_pattern};
+use bevy::ecs::{
+    entity::Entity,
+    system::{Query, Res, SystemParam},
+};
+use godot::classes::Node;
+use godot::obj::Gd;
+
+/// Finds the entities of nodes by path, unique name or wildcard pattern.
+///
+/// Nodes are looked up in Godot, then mapped to their entity through the [`GodotEntityIndex`],
+/// so lookups don't scan every named entity. Nodes without an entity (not mirrored, or not
+/// spawned yet) give `None`.
+///
+/// Relative paths and patterns start from the app's scene root, which is the scene tree root
+/// unless the `BevyApp` is scoped to a subtree. Paths starting with `/` are absolute.
+///
+/// ```ignore
+/// fn setup_hud(mut commands: Commands, mut nodes: GodotNodes, players: Query<Entity, With<Player>>) {
+///     let Some(hud) = nodes.entity_at("/root/Main/HUD") else { return };
+///     commands.entity(hud).insert(Hud);
+///
+///     for player in &players {
+///         // `%HealthBar` in the player's scene
+///         if let Some(bar) = nodes.entity_by_unique_name(player, "%HealthBar") {
+///             commands.entity(bar).insert(HealthBar);
+///         }
+///     }
+///
+///     for enemy in nodes.entities_matching("Level*/Enemies/*") {
+///         commands.entity(enemy).insert(Enemy);
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GodotNodes<'w, 's> {
+    scene_tree: SceneTreeRef<'w, 's>,
+    config: Res<'w, SceneTreeConfig>,
+    entity_index: Res<'w, GodotEntityIndex>,
+    handles: Query<'w, 's, &'static GodotNodeHandle>,
+}
+
+impl GodotNodes<'_, '_> {
+    /// The entity of the node at `path`
+    pub fn entity_at(&mut self, path: &str) -> Option<Entity> {
+        let node = self.search_root()?.get_node_or_null(path)?;
+        self.entity_index.entity_for_node(&node)
+    }
+
+    /// The entity of the node at `path`, relative to the node of `base`
+    pub fn entity_at_from(&self, base: Entity, path: &str) -> Option<Entity> {
+        let node = self.node(base)?.get_node_or_null(path)?;
+        self.entity_index.entity_for_node(&node)
+    }
+
+    /// The entity of the node with this scene unique name, within the scene `owner` belongs to
+    /// (or is the root of). The leading `%` is optional.
+    pub fn entity_by_unique_name(&self, owner: Entity, name: &str) -> Option<Entity> {
+        let name = name.strip_prefix('%').unwrap_or(name);
+        let node = self.node(owner)?.get_node_or_null(&format!("%{name}"))?;
+        self.entity_index.entity_for_node(&node)
+    }
+
+    /// The entity of the first node matching a wildcard pattern, see
+    /// [`find_node_by_pattern`] for the supported patterns
+    pub fn entity_matching(&mut self, pattern: &str) -> Option<Entity> {
+        let node = find_node_by_pattern(&self.search_root()?, pattern)?;
+        self.entity_index.entity_for_node(&node)
+    }
+
+    /// The entities of all nodes matching a wildcard pattern, in tree order
+    pub fn entities_matching(&mut self, pattern: &str) -> Vec<Entity> {
+        let Some(search_root) = self.search_root() else {
+            return Vec::new();
+        };
+        find_nodes_by_pattern(&search_root, pattern)
+            .iter()
+            .filter_map(|node| self.entity_index.entity_for_node(node))
+            .collect()
+    }
+
+    fn search_root(&mut self) -> Option<Gd<Node>> {
+        self.config
+            .scene_root()
+            .or_else(|| self.scene_tree.get().get_root().map(|root| root.upcast()))
+    }
+
+    fn node(&self, entity: Entity) -> Option<Gd<Node>> {
+        let handle = self.handles.get(entity).ok()?;
+        Gd::try_from_instance_id(handle.instance_id()).ok()
+    }
+}
@@ -1,11 +1,19 @@
+use super::filter::SceneTreeFilter;
+use super::groups::{GroupAdded, GroupRemoved, Groups, TrackGroups, sync_groups};
+use super::markers::{
+    GodotClass, MarkerPolicy, add_base_node_type_markers, add_transform_node_type_markers,
+};
+use super::meta::TrackMeta;
 use super::node_type_checking_generated::{
     add_comprehensive_node_type_markers, add_node_type_markers_from_string,
     remove_comprehensive_node_type_markers,
 };
-use crate::plugins::core::SceneTreeComponentRegistry;
+use super::scene_instance::SceneInstance;
+use crate::plugins::core::{NodeDespawnPolicy, SceneTreeComponentRegistry};
 use crate::prelude::{GodotScene, main_thread_system};
+use crate::watchers::GodotWatchers;
 use crate::{
-    interop::GodotNodeHandle,
+    interop::{GodotEntityIndex, GodotNodeHandle},
     plugins::collisions::{
         AREA_ENTERED, AREA_EXITED, BODY_ENTERED, BODY_EXITED, COLLISION_START_SIGNALS,
         CollisionEventType, Collisions,
@@ -17,20 +25,23 @@ use bevy::{
         component::Component,
         entity::Entity,
         event::{Event, EventReader, EventWriter, event_update_system},
+        hierarchy::{ChildOf, Children},
         name::Name,
+        observer::Trigger,
+        query::With,
         schedule::IntoScheduleConfigs,
-        system::{Commands, NonSendMut, Query, Res, SystemParam},
+        system::{Commands, NonSendMut, Query, Res, ResMut, SystemParam},
     },
-    prelude::Resource,
+    prelude::{OnInsert, Resource},
 };
 use godot::{
     builtin::GString,
-    classes::{Engine, Node, SceneTree},
+    classes::{Engine, Node, SceneTree, object::ConnectFlags},
     meta::ToGodot,
-    obj::{Gd, Inherits},
+    obj::{EngineBitfield, Gd, InstanceId},
     prelude::GodotConvert,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use tracing::{debug, trace, warn};
 
@@ -67,6 +78,34 @@ pub struct SceneTreeConfig {
     /// as it is incompatible, i.e., Avian Physics has its own notions
     /// for what parent/child entity relatonships mean
     pub add_child_relationship: bool,
+    /// Whether nodes moved by changing an entity's `ChildOf` keep their global transform,
+    /// like Godot's `Node.reparent`. Only used when `add_child_relationship` is enabled.
+    pub reparent_keep_global_transform: bool,
+    /// Which node type marker components mirrored entities get, see [`MarkerPolicy`]
+    pub marker_policy: MarkerPolicy,
+    /// Root of the subtree mirrored into this app, `None` mirrors the whole scene tree.
+    /// Set by `BevyApp` from its `scene_root` property.
+    pub scene_root: Option<InstanceId>,
+    /// Subtrees excluded from mirroring
+    pub filter: SceneTreeFilter,
+    /// When true, every mirrored entity gets [`TrackGroups`], so its `Groups` component is kept
+    /// up to date as the node joins or leaves groups at runtime, sending `GroupAdded` and
+    /// `GroupRemoved` events. Off by default, as it reads the groups of every mirrored node each
+    /// frame; insert `TrackGroups` on the entities that need it instead.
+    pub sync_groups: bool,
+    /// When true, every mirrored entity gets [`TrackMeta`], so components registered with
+    /// `register_meta_component` are updated, added or removed as node metadata changes at
+    /// runtime. Off by default, as it reads the metadata of every mirrored node each frame;
+    /// insert `TrackMeta` on the entities that need it instead.
+    pub sync_meta: bool,
+}
+
+impl SceneTreeConfig {
+    /// The node this app mirrors and spawns scenes under, when scoped to a subtree
+    pub fn scene_root(&self) -> Option<Gd<Node>> {
+        self.scene_root
+            .and_then(|id| Gd::try_from_instance_id(id).ok())
+    }
 }
 
 impl Plugin for GodotSceneTreePlugin {
@@ -77,8 +116,20 @@ impl Plugin for GodotSceneTreePlugin {
         app.init_non_send_resource::<SceneTreeRefImpl>()
             .insert_resource(SceneTreeConfig {
                 add_child_relationship: self.add_child_relationship,
+                reparent_keep_global_transform: true,
+                marker_policy: MarkerPolicy::All,
+                scene_root: None,
+                filter: SceneTreeFilter::default(),
+                sync_groups: false,
+                sync_meta: false,
             })
+            .init_resource::<GodotWatchers>()
+            .init_resource::<SceneTreeSyncState>()
+            .init_resource::<GodotEntityIndex>()
             .add_event::<SceneTreeEvent>()
+            .add_event::<GroupAdded>()
+            .add_event::<GroupRemoved>()
+            .add_observer(reparent_godot_node)
             .add_systems(
                 PreStartup,
                 (connect_scene_tree, initialize_scene_tree).chain(),
@@ -88,6 +139,10 @@ impl Plugin for GodotSceneTreePlugin {
                 (
                     write_scene_tree_events.before(event_update_system),
                     read_scene_tree_events.before(event_update_system),
+                    sync_child_order.after(read_scene_tree_events),
+                    sync_groups
+                        .after(read_scene_tree_events)
+                        .before(event_update_system),
                 ),
             );
     }
@@ -125,21 +180,21 @@ impl Default for SceneTreeRefImpl {
 }
 
 #[main_thread_system]
+#[allow(clippy::too_many_arguments)]
 fn initialize_scene_tree(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
-    mut entities: Query<(&mut GodotNodeHandle, Entity, Option<&ProtectedNodeEntity>)>,
+    entity_index: Res<GodotEntityIndex>,
+    protected_entities: Query<(), With<ProtectedNodeEntity>>,
     config: Res<SceneTreeConfig>,
     component_registry: Res<SceneTreeComponentRegistry>,
+    watchers: Res<GodotWatchers>,
+    mut sync_state: ResMut<SceneTreeSyncState>,
 ) {
     let root = scene_tree.get().get_root().unwrap();
 
     // Check if we have the optimized GDScript watcher for type pre-analysis
-    let optimized_watcher = root
-        .try_get_node_as::<Node>("/root/BevyAppSingleton/OptimizedSceneTreeWatcher")
-        .or_else(|| root.try_get_node_as::<Node>("BevyAppSingleton/OptimizedSceneTreeWatcher"));
-
-    let events = if let Some(mut watcher) = optimized_watcher {
+    let events = if let Some(mut watcher) = watchers.optimized_scene_tree_watcher() {
         // Use optimized GDScript watcher to analyze the initial tree with type information
         tracing::info!("Using optimized initial tree analysis with type pre-analysis");
 
@@ -174,16 +229,19 @@ fn initialize_scene_tree(
     } else {
         // Use fallback traversal without type optimization
         tracing::info!("Using fallback initial tree analysis (no type optimization)");
-        traverse_fallback(root.upcast())
+        traverse_fallback(config.scene_root().unwrap_or_else(|| root.upcast()))
     };
 
     create_scene_tree_entity(
         &mut commands,
         events,
         &mut scene_tree,
-        &mut entities,
+        &watchers,
+        &entity_index,
+        &protected_entities,
         &config,
         &component_registry,
+        &mut sync_state,
     );
 }
 
@@ -218,30 +276,23 @@ pub enum SceneTreeEventType {
     NodeAdded,
     NodeRemoved,
     NodeRenamed,
+    /// The node's `_ready` ran. Only sent for mirrored nodes that weren't ready yet when their
+    /// entity was created; query `Added<GodotNodeReady>` to see every node becoming ready.
+    NodeReady,
+    /// The children of the node were added, removed or moved, only sent for mirrored nodes when
+    /// `SceneTreeConfig::add_child_relationship` is enabled
+    ChildOrderChanged,
 }
 
 #[main_thread_system]
-fn connect_scene_tree(mut scene_tree: SceneTreeRef) {
+fn connect_scene_tree(mut scene_tree: SceneTreeRef, watchers: Res<GodotWatchers>) {
     let mut scene_tree_gd = scene_tree.get();
-    let root = scene_tree_gd.get_root().unwrap();
-
-    // Try multiple paths to find the SceneTreeWatcher - support both production and test environments
-    let watcher = root
-        .try_get_node_as::<Node>("/root/BevyAppSingleton/SceneTreeWatcher")
-        .or_else(|| {
-            // Try without the full path for test environments
-            root.try_get_node_as::<Node>("BevyAppSingleton/SceneTreeWatcher")
-        })
-        .unwrap_or_else(|| {
-            panic!("SceneTreeWatcher not found at expected paths. Make sure it exists at /root/BevyAppSingleton/SceneTreeWatcher or BevyAppSingleton/SceneTreeWatcher");
-        });
 
-    // Check if we have the optimized GDScript watcher
-    let optimized_watcher = root
-        .try_get_node_as::<Node>("/root/BevyAppSingleton/OptimizedSceneTreeWatcher")
-        .or_else(|| root.try_get_node_as::<Node>("BevyAppSingleton/OptimizedSceneTreeWatcher"));
+    let watcher = watchers.scene_tree_watcher().unwrap_or_else(|| {
+        panic!("SceneTreeWatcher not found. Make sure the GodotWatchers resource points at it");
+    });
 
-    if optimized_watcher.is_some() {
+    if watchers.optimized_scene_tree_watcher().is_some() {
         // The optimized GDScript watcher handles scene tree connections and forwards
         // pre-analyzed events to the Rust watcher (which has the MPSC sender)
         // No need to connect here - it connects automatically in its _ready()
@@ -273,31 +324,6 @@ fn connect_scene_tree(mut scene_tree: SceneTreeRef) {
     }
 }
 
-#[derive(Component, Debug)]
-pub struct Groups {
-    groups: Vec<String>,
-}
-
-impl Groups {
-    pub fn is(&self, group_name: &str) -> bool {
-        self.groups.iter().any(|name| name == group_name)
-    }
-}
-
-impl<T: Inherits<Node>> From<&Gd<T>> for Groups {
-    fn from(node: &Gd<T>) -> Self {
-        Groups {
-            groups: node
-                .clone()
-                .upcast::<Node>()
-                .get_groups()
-                .iter_shared()
-                .map(|variant| variant.to_string())
-                .collect(),
-        }
-    }
-}
-
 #[doc(hidden)]
 pub struct SceneTreeEventReader(pub std::sync::mpsc::Receiver<SceneTreeEvent>);
 
@@ -315,34 +341,66 @@ fn write_scene_tree_events(
 #[derive(Component)]
 pub struct ProtectedNodeEntity;
 
+/// Marks entities whose node has run `_ready`, which means its whole subtree is ready too.
+/// Systems reading exported properties or child nodes can filter on `Added<GodotNodeReady>`
+/// instead of `Added<GodotNodeHandle>`.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct GodotNodeReady;
+
+/// Bookkeeping carried between batches of scene tree events
+#[derive(Resource, Default)]
+struct SceneTreeSyncState {
+    /// Nodes excluded by the [`SceneTreeFilter`], so their descendants can be excluded too
+    /// by only looking at their parent
+    ignored: HashSet<InstanceId>,
+    /// Parents whose children changed order, sorted by `sync_child_order`
+    reordered_parents: HashSet<InstanceId>,
+}
+
+#[allow(clippy::too_many_arguments)]
 fn create_scene_tree_entity(
     commands: &mut Commands,
     events: impl IntoIterator<Item = SceneTreeEvent>,
     scene_tree: &mut SceneTreeRef,
-    entities: &mut Query<(&mut GodotNodeHandle, Entity, Option<&ProtectedNodeEntity>)>,
+    watchers: &GodotWatchers,
+    entity_index: &GodotEntityIndex,
+    protected_entities: &Query<(), With<ProtectedNodeEntity>>,
     config: &SceneTreeConfig,
     component_registry: &SceneTreeComponentRegistry,
+    sync_state: &mut SceneTreeSyncState,
 ) {
-    let mut ent_mapping = entities
-        .iter()
-        .map(|(reference, ent, protected)| (reference.instance_id(), (ent, protected)))
-        .collect::<HashMap<_, _>>();
-    let scene_root = scene_tree.get().get_root().unwrap();
-    let collision_watcher = scene_root
-        .try_get_node_as::<Node>("/root/BevyAppSingleton/CollisionWatcher")
-        .or_else(|| {
-            // Try without the full path for test environments
-            scene_root.try_get_node_as::<Node>("BevyAppSingleton/CollisionWatcher")
-        })
-        .unwrap_or_else(|| {
-            panic!("CollisionWatcher not found at expected paths. Make sure it exists at /root/BevyAppSingleton/CollisionWatcher or BevyAppSingleton/CollisionWatcher");
-        });
+    // Entities spawned or despawned by this batch, which only reach the index once commands apply
+    let mut pending: HashMap<InstanceId, Option<Entity>> = HashMap::new();
+    let entity_for = |pending: &HashMap<InstanceId, Option<Entity>>, instance_id| {
+        pending
+            .get(&instance_id)
+            .copied()
+            .unwrap_or_else(|| entity_index.entity_for(instance_id))
+    };
+    // Nodes outside of the app's subtree belong to other apps (or none), so they aren't mirrored
+    let scoped_root = config.scene_root();
+    let scene_root = scoped_root
+        .clone()
+        .unwrap_or_else(|| scene_tree.get().get_root().unwrap().upcast());
+    let in_scope = |node: &Gd<Node>| {
+        scoped_root
+            .as_ref()
+            .is_none_or(|scoped_root| node == scoped_root || scoped_root.is_ancestor_of(node))
+    };
+    let collision_watcher = watchers.collision_watcher().unwrap_or_else(|| {
+        panic!("CollisionWatcher not found. Make sure the GodotWatchers resource points at it");
+    });
+    let scene_tree_watcher = watchers.scene_tree_watcher().unwrap_or_else(|| {
+        panic!("SceneTreeWatcher not found. Make sure the GodotWatchers resource points at it");
+    });
+    // Nodes removed and added again within this batch, i.e. reparented
+    let mut moved = HashSet::new();
 
     for event in events.into_iter() {
         trace!(target: "godot_scene_tree_events", event = ?event);
 
         let mut node = event.node.clone();
-        let ent = ent_mapping.get(&node.instance_id()).cloned();
+        let ent = entity_for(&pending, node.instance_id());
 
         match event.event_type {
             SceneTreeEventType::NodeAdded => {
@@ -351,7 +409,64 @@ fn create_scene_tree_entity(
                     continue;
                 }
 
-                let mut ent = if let Some((ent, _)) = ent {
+                let added = node.get::<Node>();
+                if !in_scope(&added) {
+                    // Moved out of this app's subtree, which takes it out of the app like a
+                    // removal, but the node itself lives on
+                    if moved.remove(&added.instance_id())
+                        && let Some(ent) = ent
+                    {
+                        if !protected_entities.contains(ent) {
+                            commands
+                                .entity(ent)
+                                .try_insert(NodeDespawnPolicy::Keep)
+                                .try_despawn();
+                        } else {
+                            commands.entity(ent).try_insert(NodeDespawnPolicy::Keep);
+                            _strip_godot_components(commands, ent);
+                        }
+                        pending.insert(added.instance_id(), None);
+                    }
+                    continue;
+                }
+
+                // A moved node keeps its entity and components, only its place in the hierarchy
+                // changes. Filters are not evaluated again.
+                if moved.remove(&added.instance_id())
+                    && let Some(ent) = ent
+                {
+                    commands
+                        .entity(ent)
+                        .insert(Name::from(added.get_name().to_string()));
+                    if config.add_child_relationship
+                        && added.instance_id() != scene_root.instance_id()
+                    {
+                        match added
+                            .get_parent()
+                            .and_then(|parent| entity_for(&pending, parent.instance_id()))
+                        {
+                            Some(parent_entity) => {
+                                commands.entity(parent_entity).add_child(ent);
+                            }
+                            None => {
+                                commands.entity(ent).remove::<ChildOf>();
+                            }
+                        }
+                    }
+                    continue;
+                }
+
+                // Nodes are added parent first, so an ignored parent is already known
+                if added
+                    .get_parent()
+                    .is_some_and(|parent| sync_state.ignored.contains(&parent.instance_id()))
+                    || config.filter.ignores(&added)
+                {
+                    sync_state.ignored.insert(added.instance_id());
+                    continue;
+                }
+
+                let mut ent = if let Some(ent) = ent {
                     commands.entity(ent)
                 } else {
                     commands.spawn_empty()
@@ -360,13 +475,28 @@ fn create_scene_tree_entity(
                 ent.insert(GodotNodeHandle::clone(&node))
                     .insert(Name::from(node.get::<Node>().get_name().to_string()));
 
+                let class_name = added.get_class().to_string();
+
                 // Add node type marker components - use optimized version if available
-                if let Some(ref node_type_str) = event.node_type {
-                    // Use pre-analyzed type from GDScript watcher (much faster)
-                    add_node_type_markers_from_string(&mut ent, node_type_str);
+                let all_markers = match &config.marker_policy {
+                    MarkerPolicy::All => true,
+                    MarkerPolicy::Classes(classes) => {
+                        classes.iter().any(|class| added.is_class(class.as_str()))
+                    }
+                    MarkerPolicy::BaseOnly | MarkerPolicy::None => false,
+                };
+                if all_markers {
+                    if let Some(ref node_type_str) = event.node_type {
+                        // Use pre-analyzed type from GDScript watcher (much faster)
+                        add_node_type_markers_from_string(&mut ent, node_type_str);
+                    } else {
+                        // Fallback to comprehensive analysis with FFI calls
+                        add_comprehensive_node_type_markers(&mut ent, &mut node);
+                    }
+                } else if config.marker_policy != MarkerPolicy::None {
+                    add_base_node_type_markers(&mut ent, &mut node);
                 } else {
-                    // Fallback to comprehensive analysis with FFI calls
-                    add_comprehensive_node_type_markers(&mut ent, &mut node);
+                    add_transform_node_type_markers(&mut ent, &mut node);
                 }
 
                 let mut node = node.get::<Node>();
@@ -429,12 +559,69 @@ fn create_scene_tree_entity(
                 }
 
                 ent.insert(Groups::from(&node));
+                if config.sync_groups {
+                    ent.insert(TrackGroups);
+                }
+                if config.sync_meta {
+                    ent.insert(TrackMeta);
+                }
+
+                // Godot reports reorders per parent, so each mirrored node forwards its own.
+                // The connection outlives the entity, e.g. for a node removed and added again.
+                if config.add_child_relationship {
+                    let node_variant = node.to_variant();
+                    let forward_reorder = scene_tree_watcher.callable("scene_tree_event").bind(&[
+                        node_variant,
+                        SceneTreeEventType::ChildOrderChanged.to_variant(),
+                    ]);
+                    if !node.is_connected("child_order_changed", &forward_reorder) {
+                        node.connect("child_order_changed", &forward_reorder);
+                    }
+                }
+
+                // Godot readies children before their parent, so a ready node has a ready subtree.
+                // Nodes are usually ready by the time their event is processed.
+                if node.is_node_ready() {
+                    ent.insert(GodotNodeReady);
+                } else {
+                    // A node that leaves and re-enters the tree before it is ready would
+                    // otherwise connect twice, which Godot rejects with an error
+                    let forward_ready = scene_tree_watcher.callable("scene_tree_event").bind(&[
+                        node.to_variant(),
+                        SceneTreeEventType::NodeReady.to_variant(),
+                    ]);
+                    if !node.is_connected("ready", &forward_ready) {
+                        node.connect_ex("ready", &forward_ready)
+                            .flags(ConnectFlags::ONE_SHOT.ord() as u32)
+                            .done();
+                    }
+                }
 
                 // Add all components registered by plugins
-                component_registry.add_to_entity(&mut ent, &event.node);
+                component_registry.add_to_entity_of_class(&mut ent, &event.node, Some(&class_name));
+                ent.insert(GodotClass(class_name));
 
                 let ent = ent.id();
-                ent_mapping.insert(node.instance_id(), (ent, None));
+                pending.insert(node.instance_id(), Some(ent));
+
+                // Owners are added before the nodes they own, so their entity is already known
+                let scene_file_path = node.get_scene_file_path();
+                let scene_instance = if !scene_file_path.is_empty() {
+                    Some(SceneInstance {
+                        scene_file_path: scene_file_path.to_string(),
+                        root: ent,
+                    })
+                } else {
+                    node.get_owner().and_then(|owner| {
+                        entity_for(&pending, owner.instance_id()).map(|root| SceneInstance {
+                            scene_file_path: owner.get_scene_file_path().to_string(),
+                            root,
+                        })
+                    })
+                };
+                if let Some(scene_instance) = scene_instance {
+                    commands.entity(ent).insert(scene_instance);
+                }
 
                 // Try to add any registered bundles for this node type
                 super::autosync::try_add_bundles_for_node(commands, ent, &event.node);
@@ -444,31 +631,40 @@ fn create_scene_tree_entity(
                     && let Some(parent) = node.get_parent()
                 {
                     let parent_id = parent.instance_id();
-                    if let Some((parent_entity, _)) = ent_mapping.get(&parent_id) {
-                        commands.entity(*parent_entity).add_children(&[ent]);
+                    if let Some(parent_entity) = entity_for(&pending, parent_id) {
+                        commands.entity(parent_entity).add_children(&[ent]);
                     } else {
                         warn!(target: "godot_scene_tree_events",
-                            "Parent entity with ID {} not found in ent_mapping. This might indicate a missing or incorrect mapping.",
+                            "Parent entity with ID {} not found in the entity index. This might indicate a missing or incorrect mapping.",
                             parent_id);
                     }
                 }
             }
             SceneTreeEventType::NodeRemoved => {
-                if let Some((ent, prot_opt)) = ent {
-                    let protected = prot_opt.is_some();
-                    if !protected {
+                sync_state.ignored.remove(&node.instance_id());
+                if let Some(ent) = ent {
+                    // Reparenting removes and adds the node again before we get to see it, so a
+                    // node still in the tree by now has only moved
+                    if let Some(removed) = node.try_get::<Node>()
+                        && removed.is_inside_tree()
+                    {
+                        moved.insert(removed.instance_id());
+                        continue;
+                    }
+
+                    if !protected_entities.contains(ent) {
                         commands.entity(ent).despawn();
                     } else {
                         _strip_godot_components(commands, ent);
                     }
-                    ent_mapping.remove(&node.instance_id());
+                    pending.insert(node.instance_id(), None);
                 } else {
                     // Entity was already despawned (common when using queue_free)
                     trace!(target: "godot_scene_tree_events", "Entity for removed node was already despawned");
                 }
             }
             SceneTreeEventType::NodeRenamed => {
-                if let Some((ent, _)) = ent {
+                if let Some(ent) = ent {
                     commands
                         .entity(ent)
                         .insert(Name::from(node.get::<Node>().get_name().to_string()));
@@ -476,6 +672,14 @@ fn create_scene_tree_entity(
                     trace!(target: "godot_scene_tree_events", "Entity for renamed node was already despawned");
                 }
             }
+            SceneTreeEventType::NodeReady => {
+                if let Some(ent) = ent {
+                    commands.entity(ent).insert(GodotNodeReady);
+                }
+            }
+            SceneTreeEventType::ChildOrderChanged => {
+                sync_state.reordered_parents.insert(node.instance_id());
+            }
         }
     }
 }
@@ -491,27 +695,112 @@ fn _strip_godot_components(commands: &mut Commands, ent: Entity) {
     // Remove automatic markers
     entity_commands.remove::<Name>();
     entity_commands.remove::<Groups>();
+    entity_commands.remove::<GodotClass>();
+    entity_commands.remove::<SceneInstance>();
+    entity_commands.remove::<GodotNodeReady>();
     // Create a dummy handle since we're removing components anyway
     let mut dummy_handle =
         GodotNodeHandle::from_instance_id(godot::prelude::InstanceId::from_i64(0));
     remove_comprehensive_node_type_markers(&mut entity_commands, &mut dummy_handle);
 }
 
+#[allow(clippy::too_many_arguments)]
 #[main_thread_system]
 fn read_scene_tree_events(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
     mut event_reader: EventReader<SceneTreeEvent>,
-    mut entities: Query<(&mut GodotNodeHandle, Entity, Option<&ProtectedNodeEntity>)>,
+    entity_index: Res<GodotEntityIndex>,
+    protected_entities: Query<(), With<ProtectedNodeEntity>>,
     config: Res<SceneTreeConfig>,
     component_registry: Res<SceneTreeComponentRegistry>,
+    watchers: Res<GodotWatchers>,
+    mut sync_state: ResMut<SceneTreeSyncState>,
 ) {
     create_scene_tree_entity(
         &mut commands,
         event_reader.read().cloned(),
         &mut scene_tree,
-        &mut entities,
+        &watchers,
+        &entity_index,
+        &protected_entities,
         &config,
         &component_registry,
+        &mut sync_state,
     );
 }
+
+/// Sorts `Children` to match the Godot child order of parents whose children changed
+#[main_thread_system]
+fn sync_child_order(
+    mut sync_state: ResMut<SceneTreeSyncState>,
+    entity_index: Res<GodotEntityIndex>,
+    mut children: Query<&mut Children>,
+) {
+    for parent_id in sync_state.reordered_parents.drain() {
+        let (Some(parent), Ok(parent_node)) = (
+            entity_index.entity_for(parent_id),
+            Gd::<Node>::try_from_instance_id(parent_id),
+        ) else {
+            continue;
+        };
+        let Ok(mut children) = children.get_mut(parent) else {
+            continue;
+        };
+
+        let order = parent_node
+            .get_children()
+            .iter_shared()
+            .enumerate()
+            .filter_map(|(position, child)| {
+                entity_index
+                    .entity_for(child.instance_id())
+                    .map(|entity| (entity, position))
+            })
+            .collect::<HashMap<_, _>>();
+        // Children without a Godot node go last, in their current order
+        let position = |entity: &Entity| order.get(entity).copied().unwrap_or(usize::MAX);
+        if !children.is_sorted_by_key(position) {
+            children.sort_by_key(position);
+        }
+    }
+}
+
+/// Moves the Godot node when an entity is given a new parent in ECS
+#[main_thread_system]
+fn reparent_godot_node(
+    trigger: Trigger<OnInsert, ChildOf>,
+    config: Res<SceneTreeConfig>,
+    handles: Query<&GodotNodeHandle>,
+    child_of: Query<&ChildOf>,
+) {
+    if !config.add_child_relationship {
+        return;
+    }
+
+    let child = trigger.target();
+    let Ok(parent) = child_of.get(child).map(ChildOf::parent) else {
+        return;
+    };
+    let (Ok(child_handle), Ok(parent_handle)) = (handles.get(child), handles.get(parent)) else {
+        return;
+    };
+    let (Ok(mut child_node), Ok(mut parent_node)) = (
+        Gd::<Node>::try_from_instance_id(child_handle.instance_id()),
+        Gd::<Node>::try_from_instance_id(parent_handle.instance_id()),
+    ) else {
+        return;
+    };
+
+    // Mirroring the Godot hierarchy inserts `ChildOf` too, the nodes are already in place then
+    match child_node.get_parent() {
+        Some(current) if current == parent_node => {}
+        Some(_) => {
+            child_node
+                .reparent_ex(&parent_node)
+                .keep_global_transform(config.reparent_keep_global_transform)
+                .done();
+        }
+        None => parent_node.add_child(&child_node),
+    }
+}
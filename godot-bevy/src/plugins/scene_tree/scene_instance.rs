@@ -0,0 +1,29 @@
+use bevy::ecs::{component::Component, entity::Entity};
+
+/// The instanced scene a mirrored node belongs to, from `Node.scene_file_path` and `Node.owner`.
+///
+/// The root node of an instanced scene points at itself, other nodes at the root of the scene
+/// they were saved in. Nodes created from code, which have no owner, don't get this component.
+///
+/// ```ignore
+/// fn despawn_dead_enemies(mut commands: Commands, dead: Query<&SceneInstance, Added<Dead>>) {
+///     for instance in &dead {
+///         // Takes the whole enemy prefab with it
+///         commands.entity(instance.root).despawn();
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SceneInstance {
+    /// Path of the scene file, e.g. `res://enemies/goblin.tscn`
+    pub scene_file_path: String,
+    /// Entity of the instanced scene's root node
+    pub root: Entity,
+}
+
+impl SceneInstance {
+    /// Whether this entity is the root of its instanced scene
+    pub fn is_root(&self, entity: Entity) -> bool {
+        self.root == entity
+    }
+}
@@ -5,7 +5,7 @@ use bevy::{
         entity::Entity,
         event::{Event, EventWriter, event_update_system},
         schedule::IntoScheduleConfigs,
-        system::{Commands, NonSend, NonSendMut, Query, SystemParam},
+        system::{Commands, NonSend, NonSendMut, Query, Res, SystemParam},
     },
 };
 use godot::{
@@ -15,7 +15,7 @@ use godot::{
 };
 use std::sync::mpsc::Sender;
 
-use crate::interop::GodotNodeHandle;
+use crate::interop::{GodotEntityIndex, GodotNodeHandle};
 
 #[derive(Default)]
 pub struct GodotSignalsPlugin;
@@ -226,12 +226,14 @@ fn drain_global_typed_signals(world: &mut bevy::ecs::world::World) {
 pub struct TypedGodotSignals<'w, T: Event + Send + 'static> {
     /// Global type-erased sender. Provided by first `GodotTypedSignalsPlugin` added.
     typed_sender: NonSend<'w, GlobalTypedSignalSender>,
+    entity_index: Option<Res<'w, GodotEntityIndex>>,
     _marker: std::marker::PhantomData<T>,
 }
 
 impl<'w, T: Event + Send + 'static> TypedGodotSignals<'w, T> {
     /// Connect a Godot signal and map it to a typed Bevy Event `T` via `mapper`.
     /// Multiple connections are supported; each connection sends a `T` when fired.
+    /// Without a `source_entity`, the entity mirroring `node` (if any) is passed to `mapper`.
     pub fn connect_map<F>(
         &self,
         node: &mut GodotNodeHandle,
@@ -241,6 +243,11 @@ impl<'w, T: Event + Send + 'static> TypedGodotSignals<'w, T> {
     ) where
         F: FnMut(&[Variant], &GodotNodeHandle, Option<Entity>) -> T + Send + 'static,
     {
+        let source_entity = source_entity.or_else(|| {
+            self.entity_index
+                .as_ref()
+                .and_then(|index| index.entity_for(node.instance_id()))
+        });
         let mut node_ref = node.get::<Node>();
         let signal_name_copy = signal_name.to_string();
         let source_node = node.clone();
@@ -91,37 +91,32 @@ macro_rules! add_transform_sync_systems {
                         $bevy_to_godot_query,
                     ),
                 >,
+                watchers: Option<bevy::prelude::Res<$crate::watchers::GodotWatchers>>,
+                counts: Option<bevy::prelude::ResMut<$crate::plugins::diagnostics::TransformSyncCounts>>,
             ) {
                 use $crate::plugins::transforms::{IntoGodotTransform, IntoGodotTransform2D};
                 use bevy::ecs::change_detection::DetectChanges;
-                use godot::classes::{Engine, Node2D, Node3D, Object, SceneTree};
+                use godot::classes::{Node2D, Node3D, Object};
                 use godot::global::godot_print;
                 use godot::prelude::{Array, Dictionary, Gd, ToGodot};
 
-                // Try to get the BevyAppSingleton autoload for bulk optimization
-                let engine = Engine::singleton();
-                if let Some(scene_tree) = engine
-                    .get_main_loop()
-                    .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
-                {
-                    if let Some(root) = scene_tree.get_root() {
-                        if let Some(bevy_app) = root.get_node_or_null("BevyAppSingleton") {
-                            // Check if this BevyApp has the raw array methods (prefer these over bulk Dictionary methods)
-                            if bevy_app.has_method("bulk_update_transforms_3d") {
-                                // Use bulk optimization path
-                                [<post_update_godot_transforms_ $name:lower _bulk>](
-                                    change_tick,
-                                    entities,
-                                    bevy_app.upcast::<Object>(),
-                                );
-                                return;
-                            }
-                        }
+                let synced = match watchers.and_then(|watchers| watchers.bevy_app()) {
+                    // Check if this BevyApp has the raw array methods (prefer these over bulk Dictionary methods)
+                    Some(bevy_app) if bevy_app.has_method("bulk_update_transforms_3d") => {
+                        // Use bulk optimization path
+                        [<post_update_godot_transforms_ $name:lower _bulk>](
+                            change_tick,
+                            entities,
+                            bevy_app.upcast::<Object>(),
+                        )
                     }
-                }
+                    // Fallback to individual FFI calls
+                    _ => [<post_update_godot_transforms_ $name:lower _individual>](change_tick, entities),
+                };
 
-                // Fallback to individual FFI calls
-                [<post_update_godot_transforms_ $name:lower _individual>](change_tick, entities);
+                if let Some(mut counts) = counts {
+                    counts.add_to_godot(synced);
+                }
             }
 
             fn [<post_update_godot_transforms_ $name:lower _bulk>](
@@ -139,7 +134,7 @@ macro_rules! add_transform_sync_systems {
                     ),
                 >,
                 mut batch_singleton: godot::prelude::Gd<godot::classes::Object>,
-            ) {
+            ) -> usize {
                 use $crate::plugins::transforms::{IntoGodotTransform, IntoGodotTransform2D};
                 use bevy::ecs::change_detection::DetectChanges;
                 use godot::global::godot_print;
@@ -258,6 +253,8 @@ macro_rules! add_transform_sync_systems {
                         ]);
                     }
                 }
+
+                total_updates
             }
 
             fn [<post_update_godot_transforms_ $name:lower _individual>](
@@ -274,12 +271,13 @@ macro_rules! add_transform_sync_systems {
                         $bevy_to_godot_query,
                     ),
                 >,
-            ) {
+            ) -> usize {
                 use $crate::plugins::transforms::{IntoGodotTransform, IntoGodotTransform2D};
                 use bevy::ecs::change_detection::DetectChanges;
                 use godot::classes::{Node2D, Node3D};
 
                 // Original individual FFI approach
+                let mut synced = 0;
                 for (transform_ref, mut reference, metadata, (node2d, node3d)) in entities.iter_mut() {
                     // Check if we have sync information for this entity
                     if let Some(sync_tick) = metadata.last_sync_tick {
@@ -302,7 +300,10 @@ macro_rules! add_transform_sync_systems {
                         let mut obj = reference.get::<Node3D>();
                         obj.set_transform(transform_ref.to_godot_transform());
                     }
+                    synced += 1;
                 }
+
+                synced
             }
 
             $app.add_systems(bevy::app::Last, [<post_update_godot_transforms_ $name:lower>]);
@@ -323,11 +324,13 @@ macro_rules! add_transform_sync_systems {
                     ),
                     $godot_to_bevy_query
                 >,
+                counts: Option<bevy::prelude::ResMut<$crate::plugins::diagnostics::TransformSyncCounts>>,
             ) {
                 use $crate::plugins::transforms::IntoBevyTransform;
                 use bevy::ecs::change_detection::DetectChanges;
                 use godot::classes::{Node2D, Node3D};
 
+                let mut synced = 0;
                 for (mut bevy_transform, mut reference, mut metadata, (node2d, node3d)) in entities.iter_mut() {
                     let new_bevy_transform = if node2d.is_some() {
                         reference
@@ -352,8 +355,13 @@ macro_rules! add_transform_sync_systems {
                         // *user* systems do this frame. It's only the latter that we may need to copy back to
                         // Godot
                         metadata.last_sync_tick = Some(bevy_transform.last_changed());
+                        synced += 1;
                     }
                 }
+
+                if let Some(mut counts) = counts {
+                    counts.add_from_godot(synced);
+                }
             }
 
             $app.add_systems(bevy::app::PreUpdate, [<pre_update_godot_transforms_ $name:lower>]);
@@ -34,20 +34,16 @@ impl Default for GodotTransformSyncPlugin {
 
 impl Plugin for GodotTransformSyncPlugin {
     fn build(&self, app: &mut App) {
-        // Register Transform component with custom initialization that reads from Godot
-        app.register_scene_tree_component_with_init::<Transform, _>(|entity, node| {
-            let mut node_handle = node.clone(); // Clone to get mutable access
-            if let Some(node3d) = node_handle.try_get::<Node3D>() {
+        // Register Transform component, read from Godot for spatial nodes and defaulted otherwise
+        app.register_scene_tree_component::<Transform>()
+            .register_scene_tree_component_for::<Node3D, Transform, _>(|entity, node3d| {
                 entity.insert(node3d.get_transform().to_bevy_transform());
-            } else if let Some(node2d) = node_handle.try_get::<Node2D>() {
+            })
+            .register_scene_tree_component_for::<Node2D, Transform, _>(|entity, node2d| {
                 entity.insert(node2d.get_transform().to_bevy_transform());
-            } else {
-                // Fallback to default for non-spatial nodes
-                entity.insert(Transform::default());
-            }
-        })
-        // Register metadata component with default - this avoids the 1-frame delay
-        .register_scene_tree_component::<TransformSyncMetadata>();
+            })
+            // Register metadata component with default - this avoids the 1-frame delay
+            .register_scene_tree_component::<TransformSyncMetadata>();
 
         // Register the transform configuration resource with the plugin's config
         app.insert_resource(GodotTransformConfig {
@@ -1,18 +1,20 @@
 use crate::interop::GodotNodeHandle;
 use crate::interop::node_markers::{Node2DMarker, Node3DMarker};
+use crate::plugins::diagnostics::TransformSyncCounts;
 use crate::plugins::transforms::{IntoBevyTransform, IntoGodotTransform, IntoGodotTransform2D};
 use crate::prelude::main_thread_system;
+use crate::watchers::GodotWatchers;
 use bevy::ecs::change_detection::{DetectChanges, Ref};
 use bevy::ecs::query::{AnyOf, Changed};
-use bevy::ecs::system::{Query, SystemChangeTick};
+use bevy::ecs::system::{Query, Res, ResMut, SystemChangeTick};
 use bevy::prelude::Transform as BevyTransform;
-use godot::classes::{Engine, Node2D, Node3D, Object, SceneTree};
+use godot::classes::{Node2D, Node3D, Object};
 use godot::prelude::{Gd, ToGodot};
 
 use super::change_filter::TransformSyncMetadata;
 
 #[main_thread_system]
-#[tracing::instrument]
+#[tracing::instrument(skip(counts))]
 pub fn pre_update_godot_transforms(
     mut entities: Query<(
         &mut BevyTransform,
@@ -20,7 +22,9 @@ pub fn pre_update_godot_transforms(
         &mut TransformSyncMetadata,
         AnyOf<(&Node2DMarker, &Node3DMarker)>,
     )>,
+    counts: Option<ResMut<TransformSyncCounts>>,
 ) {
+    let mut synced = 0;
     for (mut bevy_transform, mut reference, mut metadata, (node2d, node3d)) in entities.iter_mut() {
         let new_bevy_transform = if node2d.is_some() {
             reference
@@ -45,12 +49,17 @@ pub fn pre_update_godot_transforms(
             // *user* systems do this frame. It's only the latter that we may need to copy back to
             // Godot
             metadata.last_sync_tick = Some(bevy_transform.last_changed());
+            synced += 1;
         }
     }
+
+    if let Some(mut counts) = counts {
+        counts.add_from_godot(synced);
+    }
 }
 
 #[main_thread_system]
-#[tracing::instrument]
+#[tracing::instrument(skip(counts))]
 pub fn post_update_godot_transforms(
     change_tick: SystemChangeTick,
     entities: Query<
@@ -62,26 +71,23 @@ pub fn post_update_godot_transforms(
         ),
         Changed<BevyTransform>,
     >,
+    watchers: Option<Res<GodotWatchers>>,
+    counts: Option<ResMut<TransformSyncCounts>>,
 ) {
-    // Try to get the BevyAppSingleton autoload for bulk optimization
-    let engine = Engine::singleton();
-    if let Some(scene_tree) = engine
-        .get_main_loop()
-        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
-        && let Some(root) = scene_tree.get_root()
-        && let Some(bevy_app) = root.get_node_or_null("BevyAppSingleton")
-    {
+    let synced = match watchers.and_then(|watchers| watchers.bevy_app()) {
         // Check if this BevyApp has the raw array methods (prefer these over bulk Dictionary methods)
-        if bevy_app.has_method("bulk_update_transforms_3d") {
+        Some(bevy_app) if bevy_app.has_method("bulk_update_transforms_3d") => {
             // Use bulk optimization path
             let _bulk_span = tracing::info_span!("using_bulk_optimization").entered();
-            post_update_godot_transforms_bulk(change_tick, entities, bevy_app.upcast::<Object>());
-            return;
+            post_update_godot_transforms_bulk(change_tick, entities, bevy_app.upcast::<Object>())
         }
-    }
+        // Fallback to individual FFI calls
+        _ => post_update_godot_transforms_individual(change_tick, entities),
+    };
 
-    // Fallback to individual FFI calls
-    post_update_godot_transforms_individual(change_tick, entities);
+    if let Some(mut counts) = counts {
+        counts.add_to_godot(synced);
+    }
 }
 
 fn post_update_godot_transforms_bulk(
@@ -96,7 +102,7 @@ fn post_update_godot_transforms_bulk(
         Changed<BevyTransform>,
     >,
     mut batch_singleton: Gd<Object>,
-) {
+) -> usize {
     let _span = tracing::info_span!("bulk_data_preparation_optimized").entered();
 
     // Pre-allocate vectors with estimated capacity to avoid reallocations
@@ -224,6 +230,8 @@ fn post_update_godot_transforms_bulk(
             );
         }
     }
+
+    total_updates
 }
 
 fn post_update_godot_transforms_individual(
@@ -237,7 +245,9 @@ fn post_update_godot_transforms_individual(
         ),
         Changed<BevyTransform>,
     >,
-) {
+) -> usize {
+    let mut synced = 0;
+
     // Original individual FFI approach
     for (transform_ref, mut reference, metadata, (node2d, node3d)) in entities.iter_mut() {
         // Check if we have sync information for this entity
@@ -259,5 +269,8 @@ fn post_update_godot_transforms_individual(
             let mut obj = reference.get::<Node3D>();
             obj.set_transform(transform_ref.to_godot_transform());
         }
+        synced += 1;
     }
+
+    synced
 }
@@ -0,0 +1,209 @@
+use crate::interop::{GodotEntityIndex, GodotNodeHandle};
+use crate::plugins::core::AppSceneTreeExt;
+use crate::prelude::main_thread_system;
+use bevy::app::{App, Last, Plugin, PreUpdate};
+use bevy::ecs::schedule::IntoScheduleConfigs;
+use bevy::ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    observer::Trigger,
+    query::{Added, Changed},
+    system::{NonSendMut, Query, Res},
+    world::OnRemove,
+};
+use godot::builtin::{Callable, Variant};
+use godot::classes::{CanvasItem, Node, Node3D};
+use godot::obj::{Gd, InstanceId};
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+/// Mirrors the `visible` property of `CanvasItem` and `Node3D` nodes. Toggling it from any
+/// system shows or hides the node at the end of the frame, no main thread access needed.
+///
+/// Added to every `CanvasItem` and `Node3D` entity by [`GodotVisibilityPlugin`], and can be
+/// inserted on other entities holding such a node.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GodotVisibility {
+    pub visible: bool,
+}
+
+impl GodotVisibility {
+    pub fn visible() -> Self {
+        Self { visible: true }
+    }
+
+    pub fn hidden() -> Self {
+        Self { visible: false }
+    }
+}
+
+impl Default for GodotVisibility {
+    fn default() -> Self {
+        Self::visible()
+    }
+}
+
+/// Keeps [`GodotVisibility`] and node visibility in sync.
+///
+/// Changes to the component are applied to the node in `Last`. With `sync_from_godot`, changes
+/// made on the Godot side (from GDScript, animations, ...) are also reported back through each
+/// node's `visibility_changed` signal and applied in `PreUpdate`. The signal is connected once
+/// per node, and disconnected when its entity is despawned or the app is dropped.
+#[derive(Default)]
+pub struct GodotVisibilityPlugin {
+    /// Also update the component when the node's visibility changes in Godot
+    pub sync_from_godot: bool,
+}
+
+impl Plugin for GodotVisibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_scene_tree_component_for::<CanvasItem, GodotVisibility, _>(|entity, node| {
+            entity.insert(GodotVisibility {
+                visible: node.is_visible(),
+            });
+        })
+        .register_scene_tree_component_for::<Node3D, GodotVisibility, _>(|entity, node| {
+            entity.insert(GodotVisibility {
+                visible: node.is_visible(),
+            });
+        })
+        .add_systems(Last, write_visibility);
+
+        if self.sync_from_godot {
+            let (sender, receiver) = channel();
+            app.insert_non_send_resource(VisibilityChangedReader(receiver))
+                .insert_non_send_resource(VisibilityConnections {
+                    sender,
+                    callables: HashMap::new(),
+                })
+                .add_observer(disconnect_visibility_changed)
+                .add_systems(
+                    PreUpdate,
+                    (connect_visibility_changed, read_visibility_changes).chain(),
+                );
+        }
+    }
+}
+
+struct VisibilityChangedReader(Receiver<InstanceId>);
+
+/// `visibility_changed` connections made for mirrored nodes. A node keeps its connections when
+/// it leaves the tree, so they are tracked here to connect each node only once.
+struct VisibilityConnections {
+    sender: Sender<InstanceId>,
+    callables: HashMap<InstanceId, Callable>,
+}
+
+impl VisibilityConnections {
+    fn disconnect(instance_id: InstanceId, callable: &Callable) {
+        if let Ok(mut node) = Gd::<Node>::try_from_instance_id(instance_id)
+            && node.is_connected("visibility_changed", callable)
+        {
+            node.disconnect("visibility_changed", callable);
+        }
+    }
+}
+
+// Despawn observers don't run when the world is dropped, e.g. when `BevyApp` drops or rebuilds
+// the app after a panic, so the remaining connections are cleaned up here
+impl Drop for VisibilityConnections {
+    fn drop(&mut self) {
+        for (instance_id, callable) in self.callables.drain() {
+            Self::disconnect(instance_id, &callable);
+        }
+    }
+}
+
+#[main_thread_system]
+fn connect_visibility_changed(
+    mut connections: NonSendMut<VisibilityConnections>,
+    added: Query<&GodotNodeHandle, Added<GodotVisibility>>,
+) {
+    for handle in &added {
+        let instance_id = handle.instance_id();
+        let Ok(mut node) = Gd::<Node>::try_from_instance_id(instance_id) else {
+            continue;
+        };
+        if connections
+            .callables
+            .get(&instance_id)
+            .is_some_and(|callable| node.is_connected("visibility_changed", callable))
+        {
+            continue;
+        }
+
+        let sender = connections.sender.clone();
+        let callable = Callable::from_local_fn("visibility_changed", move |_args| {
+            let _ = sender.send(instance_id);
+            Ok(Variant::nil())
+        });
+        node.connect("visibility_changed", &callable);
+        connections.callables.insert(instance_id, callable);
+    }
+}
+
+#[main_thread_system]
+fn disconnect_visibility_changed(
+    trigger: Trigger<OnRemove, GodotVisibility>,
+    handles: Query<&GodotNodeHandle>,
+    mut connections: NonSendMut<VisibilityConnections>,
+) {
+    let Ok(handle) = handles.get(trigger.target()) else {
+        return;
+    };
+    if let Some(callable) = connections.callables.remove(&handle.instance_id()) {
+        VisibilityConnections::disconnect(handle.instance_id(), &callable);
+    }
+}
+
+#[main_thread_system]
+fn read_visibility_changes(
+    changes: NonSendMut<VisibilityChangedReader>,
+    entity_index: Res<GodotEntityIndex>,
+    mut visibilities: Query<&mut GodotVisibility>,
+) {
+    for instance_id in changes.0.try_iter() {
+        let (Some(entity), Some(visible)) = (
+            entity_index.entity_for(instance_id),
+            node_visibility(instance_id),
+        ) else {
+            continue;
+        };
+        if let Ok(mut visibility) = visibilities.get_mut(entity) {
+            // Parents changing visibility notify their children too, leaving them unchanged
+            visibility.set_if_neq(GodotVisibility { visible });
+        }
+    }
+}
+
+#[main_thread_system]
+fn write_visibility(
+    visibilities: Query<(&GodotNodeHandle, &GodotVisibility), Changed<GodotVisibility>>,
+) {
+    for (handle, visibility) in &visibilities {
+        let Ok(node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+            continue;
+        };
+
+        if let Ok(mut canvas_item) = node.clone().try_cast::<CanvasItem>() {
+            if canvas_item.is_visible() != visibility.visible {
+                canvas_item.set_visible(visibility.visible);
+            }
+        } else if let Ok(mut node3d) = node.try_cast::<Node3D>()
+            && node3d.is_visible() != visibility.visible
+        {
+            node3d.set_visible(visibility.visible);
+        }
+    }
+}
+
+fn node_visibility(instance_id: InstanceId) -> Option<bool> {
+    let node = Gd::<Node>::try_from_instance_id(instance_id).ok()?;
+    match node.try_cast::<CanvasItem>() {
+        Ok(canvas_item) => Some(canvas_item.is_visible()),
+        Err(node) => node
+            .try_cast::<Node3D>()
+            .ok()
+            .map(|node3d| node3d.is_visible()),
+    }
+}
@@ -3,6 +3,8 @@ pub use crate::interop::*;
 pub use crate::node_tree_view::NodeTreeView;
 #[cfg(feature = "godot_bevy_log")]
 pub use crate::plugins::godot_bevy_logger::GodotBevyLogPlugin;
+#[cfg(feature = "bevy_state")]
+pub use crate::plugins::scene_state::{GodotSceneStatePlugin, SceneTransitionComplete};
 pub use crate::plugins::{
     // Signals
     GodotCorePlugins,
@@ -18,7 +20,13 @@ pub use crate::plugins::{
         AREA_ENTERED, AREA_EXITED, BODY_ENTERED, BODY_EXITED, COLLISION_START_SIGNALS,
         CollisionEvent, CollisionEventType, Collisions, GodotCollisionsPlugin,
     },
-    core::{FindEntityByNameExt, MainThreadMarker, PhysicsDelta, PhysicsUpdate},
+    core::{
+        AppLifecycleEvent, AppPanic, AppPanicPhase, AppSceneTreeExt, FindEntityByNameExt,
+        FixedUpdateMode, GodotFixedUpdateConfig, GodotLifecycleConfig, GodotLifecyclePlugin,
+        GodotTimePlugin, MainThreadMarker, NodeDespawnPolicy, PanicPolicy, PhysicsDelta,
+        PhysicsUpdate, ProcessDelta,
+    },
+    diagnostics::{GodotDiagnosticsPlugin, GodotPerformanceDiagnosticsPlugin},
     // Collisions
     input::{
         ActionInput, BevyInputBridgePlugin, GodotInputEventPlugin, KeyboardInput, MouseButtonInput,
@@ -26,9 +34,15 @@ pub use crate::plugins::{
     },
     // Core functionality
     packed_scene::{GodotPackedScenePlugin, GodotScene},
+    process_mode::{
+        ActiveWhenTreePaused, GodotPauseState, GodotProcessMode, GodotProcessModePlugin,
+        tree_paused,
+    },
     // Input
     scene_tree::{
-        AutoSyncBundleRegistry, GodotSceneTreePlugin, Groups, SceneTreeConfig, SceneTreeRef,
+        AutoSyncBundleRegistry, GodotClass, GodotNodeReady, GodotNodes, GodotSceneTreePlugin,
+        GroupAdded, GroupRemoved, Groups, MarkerPolicy, SceneInstance, SceneTreeConfig,
+        SceneTreeFilter, SceneTreeRef, TrackGroups, TrackMeta,
     },
     signals::{GodotTypedSignalsPlugin, TypedGodotSignals},
     // Scene tree
@@ -36,6 +50,7 @@ pub use crate::plugins::{
         GodotTransformConfig, GodotTransformSyncPlugin, GodotTransformSyncPluginExt,
         TransformSyncMetadata, TransformSyncMode, add_transform_sync_systems,
     },
+    visibility::{GodotVisibility, GodotVisibilityPlugin},
 };
 
 // Legacy re-exports (deprecated). Keep available for downstreams while avoiding warnings here.
@@ -1,3 +1,42 @@
+use bevy::prelude::Resource;
+use godot::classes::Node;
+use godot::obj::{Gd, InstanceId};
+
 pub mod collision_watcher;
 pub mod input_watcher;
 pub mod scene_tree_watcher;
+
+/// Locations of the `BevyApp` node and the watcher nodes it owns.
+///
+/// `BevyApp` fills this in when it builds the app, so systems find the watchers wherever the
+/// app node lives in the tree (autoload, renamed, or instanced inside a scene) instead of
+/// looking them up by path.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct GodotWatchers {
+    pub bevy_app: Option<InstanceId>,
+    pub scene_tree_watcher: Option<InstanceId>,
+    pub optimized_scene_tree_watcher: Option<InstanceId>,
+    pub collision_watcher: Option<InstanceId>,
+}
+
+impl GodotWatchers {
+    pub fn bevy_app(&self) -> Option<Gd<Node>> {
+        Self::lookup(self.bevy_app)
+    }
+
+    pub fn scene_tree_watcher(&self) -> Option<Gd<Node>> {
+        Self::lookup(self.scene_tree_watcher)
+    }
+
+    pub fn optimized_scene_tree_watcher(&self) -> Option<Gd<Node>> {
+        Self::lookup(self.optimized_scene_tree_watcher)
+    }
+
+    pub fn collision_watcher(&self) -> Option<Gd<Node>> {
+        Self::lookup(self.collision_watcher)
+    }
+
+    fn lookup(instance_id: Option<InstanceId>) -> Option<Gd<Node>> {
+        instance_id.and_then(|id| Gd::try_from_instance_id(id).ok())
+    }
+}
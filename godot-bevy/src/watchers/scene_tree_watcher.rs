@@ -9,7 +9,7 @@ use crate::{
 };
 
 #[derive(GodotClass)]
-#[class(base=Node)]
+#[class(tool, base=Node)]
 pub struct SceneTreeWatcher {
     base: Base<Node>,
     pub notification_channel: Option<Sender<SceneTreeEvent>>,
@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use godot::prelude::*;
 use godot_bevy::interop::GodotNodeHandle;
+use godot_bevy::plugins::core::NodeDespawnPolicy;
 use godot_bevy_testability::*;
 
 use crate::scene_tree::utils::{
@@ -169,3 +170,156 @@ pub fn test_entity_despawn_frees_node(ctx: &mut BevyGodotTestContext) -> TestRes
 
     Ok(())
 }
+
+/// Test that despawning an entity with `NodeDespawnPolicy::Detach` removes its node from the
+/// tree without freeing it
+pub fn test_entity_despawn_detaches_node(ctx: &mut BevyGodotTestContext) -> TestResult<()> {
+    use godot_bevy_testability::BevyGodotTestContextExt;
+
+    let mut env = ctx.setup_full_integration();
+
+    let mut node = godot::classes::Node3D::new_alloc();
+    node.set_name("NodeToDetach");
+    let node_id = node.instance_id();
+
+    env.add_node_to_scene(node.clone());
+    ctx.app.update();
+
+    let entity = find_entity_for_node(ctx, node_id).expect("Entity should be created initially");
+    let world = ctx.app.world_mut();
+    world.entity_mut(entity).insert(NodeDespawnPolicy::Detach);
+    world.despawn(entity);
+
+    for _i in 0..5 {
+        ctx.app.update();
+    }
+
+    // ASSERTION: The node is still alive, but out of the tree
+    if !node_id.lookup_validity() {
+        return Err(TestError::assertion("Detached node should not be freed"));
+    }
+    if node.is_inside_tree() || node.get_parent().is_some() {
+        return Err(TestError::assertion(
+            "Detached node should be removed from its parent",
+        ));
+    }
+
+    // ASSERTION: The entity is gone
+    if ctx.app.world().get_entity(entity).is_ok() {
+        return Err(TestError::assertion(
+            "Entity should be despawned after detaching its node",
+        ));
+    }
+
+    // Detached nodes are ours to free
+    node.free();
+
+    Ok(())
+}
+
+/// Test that with `NodeDespawnPolicy::Keep` as the default, despawning leaves the node in place
+pub fn test_entity_despawn_keeps_node(ctx: &mut BevyGodotTestContext) -> TestResult<()> {
+    use godot_bevy_testability::BevyGodotTestContextExt;
+
+    let mut env = ctx.setup_full_integration();
+    ctx.app.insert_resource(NodeDespawnPolicy::Keep);
+
+    let mut node = godot::classes::Node3D::new_alloc();
+    node.set_name("NodeToKeep");
+    let node_id = node.instance_id();
+
+    let root = env.scene_tree.get_root().unwrap();
+    env.add_node_to_scene(node.clone());
+    ctx.app.update();
+
+    let entity = find_entity_for_node(ctx, node_id).expect("Entity should be created initially");
+    ctx.app.world_mut().despawn(entity);
+
+    for _i in 0..5 {
+        ctx.app.update();
+    }
+
+    // ASSERTION: The node is untouched
+    if !node_id.lookup_validity() {
+        return Err(TestError::assertion("Kept node should not be freed"));
+    }
+    if node.get_parent() != Some(root.upcast()) {
+        return Err(TestError::assertion(
+            "Kept node should stay under its parent",
+        ));
+    }
+
+    // ASSERTION: Only the ECS side went away
+    if ctx.app.world().get_entity(entity).is_ok() {
+        return Err(TestError::assertion("Entity should be despawned"));
+    }
+
+    node.queue_free();
+
+    Ok(())
+}
+
+/// Test that child entities despawned along with a detached parent keep their nodes, so the
+/// detached subtree stays intact
+pub fn test_entity_despawn_children_follow_parent_policy(
+    ctx: &mut BevyGodotTestContext,
+) -> TestResult<()> {
+    use godot_bevy_testability::BevyGodotTestContextExt;
+
+    let mut env = ctx.setup_full_integration();
+
+    let mut parent = godot::classes::Node3D::new_alloc();
+    parent.set_name("DetachedParent");
+    let mut child = godot::classes::Node3D::new_alloc();
+    child.set_name("DetachedChild");
+    parent.add_child(&child);
+    let (parent_id, child_id) = (parent.instance_id(), child.instance_id());
+
+    env.add_node_to_scene(parent.clone());
+    ctx.app.update();
+
+    let parent_entity =
+        find_entity_for_node(ctx, parent_id).expect("Parent entity should be created initially");
+    let child_entity =
+        find_entity_for_node(ctx, child_id).expect("Child entity should be created initially");
+
+    // Despawning the parent despawns its children too
+    let world = ctx.app.world_mut();
+    world
+        .entity_mut(parent_entity)
+        .insert(NodeDespawnPolicy::Detach);
+    world.despawn(parent_entity);
+
+    for _i in 0..5 {
+        ctx.app.update();
+    }
+
+    let world = ctx.app.world();
+    if world.get_entity(parent_entity).is_ok() || world.get_entity(child_entity).is_ok() {
+        return Err(TestError::assertion(
+            "Parent and child entities should both be despawned",
+        ));
+    }
+
+    // ASSERTION: The parent node is detached, the child node still under it
+    if !parent_id.lookup_validity() || !child_id.lookup_validity() {
+        return Err(TestError::assertion(
+            "Nodes of a detached subtree should not be freed",
+        ));
+    }
+    if parent.is_inside_tree() {
+        return Err(TestError::assertion(
+            "Parent node should be removed from the tree",
+        ));
+    }
+    if child.get_parent() != Some(parent.clone().upcast()) {
+        return Err(TestError::assertion(
+            "Child node should stay under its detached parent",
+        ));
+    }
+
+    // Frees the child as well
+    parent.free();
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Tests for mirroring the node hierarchy as `ChildOf`/`Children`, in both directions
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot_bevy_testability::*;
+
+use crate::scene_tree::utils::find_entity_for_node;
+
+fn parent_of(ctx: &mut BevyGodotTestContext, entity: Entity) -> Option<Entity> {
+    ctx.app.world().get::<ChildOf>(entity).map(ChildOf::parent)
+}
+
+/// Test that `reparent()` in Godot keeps the entity and updates its `ChildOf`
+pub fn test_godot_reparent_updates_child_of(ctx: &mut BevyGodotTestContext) -> TestResult<()> {
+    use godot_bevy_testability::BevyGodotTestContextExt;
+
+    println!("\n=== GODOT REPARENT TEST ===");
+
+    let mut env = ctx.setup_full_integration();
+
+    let mut first_parent = godot::classes::Node3D::new_alloc();
+    first_parent.set_name("FirstParent");
+    let mut second_parent = godot::classes::Node3D::new_alloc();
+    second_parent.set_name("SecondParent");
+    let mut child = godot::classes::Node3D::new_alloc();
+    child.set_name("MovedChild");
+    first_parent.add_child(&child);
+
+    env.add_node_to_scene(first_parent.clone());
+    env.add_node_to_scene(second_parent.clone());
+    ctx.app.update();
+
+    let child_entity = find_entity_for_node(ctx, child.instance_id())
+        .ok_or_else(|| TestError::assertion("Could not find entity for child node"))?;
+    let first_entity = find_entity_for_node(ctx, first_parent.instance_id())
+        .ok_or_else(|| TestError::assertion("Could not find entity for first parent"))?;
+    let second_entity = find_entity_for_node(ctx, second_parent.instance_id())
+        .ok_or_else(|| TestError::assertion("Could not find entity for second parent"))?;
+
+    if parent_of(ctx, child_entity) != Some(first_entity) {
+        return Err(TestError::assertion(
+            "Child entity should start out as a child of the first parent",
+        ));
+    }
+
+    child.reparent(&second_parent);
+    ctx.app.update();
+
+    // ASSERTION: The node keeps its entity
+    if find_entity_for_node(ctx, child.instance_id()) != Some(child_entity) {
+        return Err(TestError::assertion(
+            "Reparented node should keep its entity",
+        ));
+    }
+
+    // ASSERTION: ChildOf follows the new Godot parent
+    if parent_of(ctx, child_entity) != Some(second_entity) {
+        return Err(TestError::assertion(
+            "ChildOf should point at the new parent after reparent()",
+        ));
+    }
+    println!("✓ reparent() moved the entity under the new parent entity");
+
+    first_parent.queue_free();
+    second_parent.queue_free();
+
+    println!("=== END GODOT REPARENT TEST ===\n");
+
+    Ok(())
+}
+
+/// Test that giving an entity a new parent in ECS moves its Godot node
+pub fn test_ecs_reparent_moves_node(ctx: &mut BevyGodotTestContext) -> TestResult<()> {
+    use godot_bevy_testability::BevyGodotTestContextExt;
+
+    println!("\n=== ECS REPARENT TEST ===");
+
+    let mut env = ctx.setup_full_integration();
+
+    let mut first_parent = godot::classes::Node3D::new_alloc();
+    first_parent.set_name("FirstParent");
+    let mut second_parent = godot::classes::Node3D::new_alloc();
+    second_parent.set_name("SecondParent");
+    let child = godot::classes::Node3D::new_alloc();
+    first_parent.add_child(&child);
+
+    env.add_node_to_scene(first_parent.clone());
+    env.add_node_to_scene(second_parent.clone());
+    ctx.app.update();
+
+    let child_entity = find_entity_for_node(ctx, child.instance_id())
+        .ok_or_else(|| TestError::assertion("Could not find entity for child node"))?;
+    let second_entity = find_entity_for_node(ctx, second_parent.instance_id())
+        .ok_or_else(|| TestError::assertion("Could not find entity for second parent"))?;
+
+    // Runs the `reparent_godot_node` observer
+    ctx.app
+        .world_mut()
+        .entity_mut(second_entity)
+        .add_child(child_entity);
+
+    // ASSERTION: The Godot node moved right away
+    if child.get_parent() != Some(second_parent.clone().upcast()) {
+        return Err(TestError::assertion(
+            "Node should be moved under the new parent's node",
+        ));
+    }
+    println!("✓ add_child moved the Godot node");
+
+    // Godot reports the move back, which must not undo it or respawn the entity
+    ctx.app.update();
+
+    if find_entity_for_node(ctx, child.instance_id()) != Some(child_entity) {
+        return Err(TestError::assertion("Moved node should keep its entity"));
+    }
+    if parent_of(ctx, child_entity) != Some(second_entity) {
+        return Err(TestError::assertion(
+            "ChildOf should still point at the new parent after the move is mirrored",
+        ));
+    }
+    println!("✓ Entity kept its new parent after the move was mirrored");
+
+    first_parent.queue_free();
+    second_parent.queue_free();
+
+    println!("=== END ECS REPARENT TEST ===\n");
+
+    Ok(())
+}
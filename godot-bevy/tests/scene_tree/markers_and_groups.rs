@@ -3,7 +3,9 @@
 use bevy::prelude::*;
 use godot::prelude::*;
 use godot_bevy::interop::{GodotNodeHandle, node_markers::*};
-use godot_bevy::plugins::scene_tree::{Groups, plugin::ProtectedNodeEntity};
+use godot_bevy::plugins::scene_tree::{
+    GroupAdded, GroupRemoved, Groups, TrackGroups, plugin::ProtectedNodeEntity,
+};
 use godot_bevy_testability::*;
 
 use crate::scene_tree::utils::{count_entities_with, entity_has_component, find_entity_for_node};
@@ -276,6 +278,101 @@ pub fn test_node_groups_component(ctx: &mut BevyGodotTestContext) -> TestResult<
     Ok(())
 }
 
+/// Test that `add_to_group`/`remove_from_group` at runtime update `Groups` and send events
+/// for entities with `TrackGroups`, and leave untracked entities alone
+pub fn test_runtime_group_changes(ctx: &mut BevyGodotTestContext) -> TestResult<()> {
+    use godot_bevy_testability::BevyGodotTestContextExt;
+
+    println!("\n=== RUNTIME GROUP CHANGES TEST ===");
+
+    let mut env = ctx.setup_full_integration();
+
+    let mut node = godot::classes::Node3D::new_alloc();
+    node.set_name("RuntimeGroupNode");
+    let node_id = node.instance_id();
+    env.add_node_to_scene(node.clone());
+    let mut untracked_node = godot::classes::Node3D::new_alloc();
+    untracked_node.set_name("UntrackedGroupNode");
+    let untracked_id = untracked_node.instance_id();
+    env.add_node_to_scene(untracked_node.clone());
+    ctx.app.update();
+
+    let entity = find_entity_for_node(ctx, node_id)
+        .ok_or_else(|| TestError::assertion("Could not find entity for runtime group node"))?;
+    let untracked_entity = find_entity_for_node(ctx, untracked_id)
+        .ok_or_else(|| TestError::assertion("Could not find entity for untracked node"))?;
+    ctx.app.world_mut().entity_mut(entity).insert(TrackGroups);
+
+    // Join a group after the entities exist
+    node.add_to_group("enemies");
+    untracked_node.add_to_group("enemies");
+    ctx.app.update();
+
+    let world = ctx.app.world();
+    if !world
+        .get::<Groups>(entity)
+        .is_some_and(|groups| groups.is("enemies"))
+    {
+        return Err(TestError::assertion(
+            "Groups component not updated after add_to_group",
+        ));
+    }
+    let events = world.resource::<Events<GroupAdded>>();
+    if !events
+        .get_cursor()
+        .read(events)
+        .any(|event| event.entity == entity && event.group == "enemies")
+    {
+        return Err(TestError::assertion("No GroupAdded event for add_to_group"));
+    }
+    println!("✓ add_to_group updated Groups and sent GroupAdded");
+
+    // ASSERTION: Nodes without TrackGroups aren't polled
+    if ctx
+        .app
+        .world()
+        .get::<Groups>(untracked_entity)
+        .is_none_or(|groups| groups.is("enemies"))
+    {
+        return Err(TestError::assertion(
+            "Groups of an entity without TrackGroups should not be polled",
+        ));
+    }
+    println!("✓ Untracked entity kept its initial Groups");
+
+    // And leave it again
+    node.remove_from_group("enemies");
+    ctx.app.update();
+
+    let world = ctx.app.world();
+    if world
+        .get::<Groups>(entity)
+        .is_none_or(|groups| groups.is("enemies"))
+    {
+        return Err(TestError::assertion(
+            "Groups component not updated after remove_from_group",
+        ));
+    }
+    let events = world.resource::<Events<GroupRemoved>>();
+    if !events
+        .get_cursor()
+        .read(events)
+        .any(|event| event.entity == entity && event.group == "enemies")
+    {
+        return Err(TestError::assertion(
+            "No GroupRemoved event for remove_from_group",
+        ));
+    }
+    println!("✓ remove_from_group updated Groups and sent GroupRemoved");
+
+    node.queue_free();
+    untracked_node.queue_free();
+
+    println!("=== END RUNTIME GROUP CHANGES TEST ===\n");
+
+    Ok(())
+}
+
 /// Test that ProtectedNodeEntity prevents entity deletion
 pub fn test_protected_entity_deletion(ctx: &mut BevyGodotTestContext) -> TestResult<()> {
     use godot_bevy_testability::BevyGodotTestContextExt;
@@ -10,6 +10,7 @@ use godot_bevy_testability::*;
 
 mod scene_tree {
     pub mod entity_lifecycle;
+    pub mod hierarchy;
     pub mod markers_and_groups;
     pub mod multiple_nodes;
     pub mod name_synchronization;
@@ -18,10 +19,14 @@ mod scene_tree {
 
 // Import test functions
 use scene_tree::entity_lifecycle::{
-    test_entity_despawn_frees_node, test_node_creates_entity, test_node_deletion_removes_entity,
+    test_entity_despawn_children_follow_parent_policy, test_entity_despawn_detaches_node,
+    test_entity_despawn_frees_node, test_entity_despawn_keeps_node, test_node_creates_entity,
+    test_node_deletion_removes_entity,
 };
+use scene_tree::hierarchy::{test_ecs_reparent_moves_node, test_godot_reparent_updates_child_of};
 use scene_tree::markers_and_groups::{
     test_node_groups_component, test_node_type_markers, test_protected_entity_deletion,
+    test_runtime_group_changes,
 };
 use scene_tree::multiple_nodes::{
     test_different_node_types_create_entities, test_multiple_nodes_create_multiple_entities,
@@ -35,6 +40,9 @@ bevy_godot_test_main! {
     test_node_creates_entity,
     test_node_deletion_removes_entity,
     test_entity_despawn_frees_node,
+    test_entity_despawn_detaches_node,
+    test_entity_despawn_keeps_node,
+    test_entity_despawn_children_follow_parent_policy,
 
     // Multiple nodes and different types
     test_multiple_nodes_create_multiple_entities,
@@ -47,5 +55,10 @@ bevy_godot_test_main! {
     // Node type markers and groups
     test_node_type_markers,
     test_node_groups_component,
+    test_runtime_group_changes,
     test_protected_entity_deletion,
+
+    // Hierarchy mirroring
+    test_godot_reparent_updates_child_of,
+    test_ecs_reparent_moves_node,
 }
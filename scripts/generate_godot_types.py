@@ -459,10 +459,10 @@ var rust_watcher: Node = null
 func _ready():
 	name = "OptimizedSceneTreeWatcher"
 	
-	# Auto-detect the Rust SceneTreeWatcher
-	var bevy_app = get_node("/root/BevyAppSingleton")
+	# Auto-detect the Rust SceneTreeWatcher, a sibling under the BevyApp node
+	var bevy_app = get_parent()
 	if bevy_app:
-		rust_watcher = bevy_app.get_node("SceneTreeWatcher")
+		rust_watcher = bevy_app.get_node_or_null("SceneTreeWatcher")
 	
 	# Connect to scene tree signals - these will forward to Rust with type info
 	# Use immediate connections for add/remove to get events as early as possible